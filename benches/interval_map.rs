@@ -0,0 +1,71 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use osb::utils::IntervalMap;
+
+// Deterministic xorshift generator, so that every run benchmarks the same insertions
+fn random_ranges(count: usize) -> Vec<(i32, i32)> {
+    let mut state: u32 = 0x2545_f491;
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let start = (state % 600_000) as i32;
+            (start, start + 500 + (state % 5_000) as i32)
+        })
+        .collect()
+}
+
+// Ranges as a generator usually pushes them: one after the other, slightly overlapping
+fn monotonic_ranges(count: usize) -> Vec<(i32, i32)> {
+    (0..count as i32)
+        .map(|i| (i * 100, i * 100 + 150))
+        .collect()
+}
+
+fn fill(ranges: &[(i32, i32)]) -> IntervalMap<i32, usize> {
+    let mut interval_map = IntervalMap::new();
+    for (i, &(start, end)) in ranges.iter().enumerate() {
+        interval_map.push(start..end, i);
+    }
+    interval_map
+}
+
+fn push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("IntervalMap::push");
+    for &count in &[1_000, 10_000] {
+        let monotonic = monotonic_ranges(count);
+        group.bench_with_input(
+            BenchmarkId::new("monotonic", count),
+            &monotonic,
+            |b, ranges| b.iter(|| fill(black_box(ranges))),
+        );
+
+        let random = random_ranges(count);
+        group.bench_with_input(BenchmarkId::new("random", count), &random, |b, ranges| {
+            b.iter(|| fill(black_box(ranges)))
+        });
+    }
+    group.finish();
+}
+
+fn get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("IntervalMap::get");
+    for (name, ranges) in &[
+        ("monotonic", monotonic_ranges(10_000)),
+        ("random", random_ranges(10_000)),
+    ] {
+        let interval_map = fill(ranges);
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                (0..1_000_000)
+                    .step_by(997)
+                    .map(|time| interval_map.get(black_box(&time)).count())
+                    .sum::<usize>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, push, get);
+criterion_main!(benches);
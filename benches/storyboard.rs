@@ -0,0 +1,37 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use osb::{Easing, Layer, Module, Sprite, Storyboard};
+use std::f32::consts::PI;
+
+// A storyboard of 10 000 sprites holding 100 events each, 1 000 000 events total
+fn storyboard() -> Storyboard {
+    let mut sb = Storyboard::new();
+    let mut module = Module::new(Layer::Background);
+    for i in 0..10_000 {
+        let mut sprite = Sprite::new("sb/particle.png");
+        let offset = i * 10;
+        for j in 0..25 {
+            let time = offset + j * 400;
+            sprite
+                .move_((Easing::QuadOut, time, time + 400, 0, 0, 640, 480))
+                .fade_((time, time + 200, 0, 1))
+                .scale_((time, 0.5))
+                .rotate_((Easing::SineInOut, time, time + 400, 0., PI));
+        }
+        module.push(sprite);
+    }
+    sb.push(module);
+    sb
+}
+
+fn render(c: &mut Criterion) {
+    let sb = storyboard();
+    let mut group = c.benchmark_group("Storyboard");
+    group.sample_size(10);
+    group.bench_function("render 1M events", |b| {
+        b.iter(|| black_box(&sb).to_string())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
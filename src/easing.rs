@@ -1,10 +1,15 @@
-use crate::utils::Number;
-use std::f32::consts::PI;
+use crate::math;
+use crate::utils::{Color, Number, Vec2};
+use crate::EasingParsingError;
+use alloc::string::String;
+use core::f32::consts::PI;
+use core::hash::{Hash, Hasher};
 
 /// `Easing`s as defined in the [official osu! specifications](https://osu.ppy.sh/wiki/en/Storyboard_Scripting/Commands)
 ///
 /// If you're interested in learning more about easing functions, how they work and what they are corresponding to, we'd suggest you take a look at [easing.net](https://easings.net/)
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Easing {
     /// The default `Easing` on osu!'s official editor
@@ -51,6 +56,75 @@ pub enum Easing {
     BounceInOut,
 }
 
+/// The direction of an [`Easing`], telling at which end of the transition the changes are slowed
+/// down
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EasingDirection {
+    /// The changes happen at a constant speed
+    Linear,
+    /// The changes happen slowly at first
+    In,
+    /// The changes slow down toward the end
+    Out,
+    /// The changes happen slowly both at first and toward the end
+    InOut,
+}
+
+/// The family of an [`Easing`], that is the kind of curve it follows regardless of its direction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EasingFamily {
+    Linear,
+    Quad,
+    Cubic,
+    Quart,
+    Quint,
+    Sine,
+    Expo,
+    Circ,
+    Elastic,
+    Back,
+    Bounce,
+}
+
+// Every `Easing`, in the order of their ids
+const EASINGS: [Easing; 35] = [
+    Easing::Linear,
+    Easing::Out,
+    Easing::In,
+    Easing::QuadIn,
+    Easing::QuadOut,
+    Easing::QuadInOut,
+    Easing::CubicIn,
+    Easing::CubicOut,
+    Easing::CubicInOut,
+    Easing::QuartIn,
+    Easing::QuartOut,
+    Easing::QuartInOut,
+    Easing::QuintIn,
+    Easing::QuintOut,
+    Easing::QuintInOut,
+    Easing::SineIn,
+    Easing::SineOut,
+    Easing::SineInOut,
+    Easing::ExpoIn,
+    Easing::ExpoOut,
+    Easing::ExpoInOut,
+    Easing::CircIn,
+    Easing::CircOut,
+    Easing::CircInOut,
+    Easing::ElasticIn,
+    Easing::ElasticOut,
+    Easing::ElasticHalfOut,
+    Easing::ElasticQuarterOut,
+    Easing::ElasticInOut,
+    Easing::BackIn,
+    Easing::BackOut,
+    Easing::BackInOut,
+    Easing::BounceIn,
+    Easing::BounceOut,
+    Easing::BounceInOut,
+];
+
 impl PartialEq for Easing {
     /// This method tests for `self` and `other` values to be equal, and is used by `==`.
     ///
@@ -80,6 +154,22 @@ impl PartialEq for Easing {
     }
 }
 
+impl Eq for Easing {}
+
+impl Hash for Easing {
+    /// Easings considered equal by `==` share the same hash
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Easing::Out => Easing::QuadOut,
+            Easing::In => Easing::QuadIn,
+            Easing::ElasticHalfOut | Easing::ElasticQuarterOut => Easing::ElasticOut,
+            easing => *easing,
+        }
+        .id()
+        .hash(state);
+    }
+}
+
 impl Easing {
     /// A method to retrieve an `Easing` type from an `id` as defined in the osu!'s specifications
     ///
@@ -130,6 +220,71 @@ impl Easing {
         }
     }
 
+    /// Retrieves an `Easing` from its name, ignoring case
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Easing;
+    /// assert_eq!(Easing::from_name("QuadInOut"), Ok(Easing::QuadInOut));
+    /// assert_eq!(Easing::from_name("bounceout"), Ok(Easing::BounceOut));
+    /// assert!(Easing::from_name("Wobbly").is_err());
+    /// ```
+    pub fn from_name(name: &str) -> Result<Easing, EasingParsingError> {
+        EASINGS
+            .iter()
+            .find(|easing| easing.name().eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or_else(|| EasingParsingError(String::from(name)))
+    }
+
+    /// Returns the name of an `Easing`, which is the name of its variant
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Easing;
+    /// assert_eq!(Easing::QuadInOut.name(), "QuadInOut");
+    /// assert_eq!(Easing::from_name(Easing::Out.name()).unwrap().id(), Easing::Out.id());
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            Easing::Linear => "Linear",
+            Easing::Out => "Out",
+            Easing::In => "In",
+            Easing::QuadIn => "QuadIn",
+            Easing::QuadOut => "QuadOut",
+            Easing::QuadInOut => "QuadInOut",
+            Easing::CubicIn => "CubicIn",
+            Easing::CubicOut => "CubicOut",
+            Easing::CubicInOut => "CubicInOut",
+            Easing::QuartIn => "QuartIn",
+            Easing::QuartOut => "QuartOut",
+            Easing::QuartInOut => "QuartInOut",
+            Easing::QuintIn => "QuintIn",
+            Easing::QuintOut => "QuintOut",
+            Easing::QuintInOut => "QuintInOut",
+            Easing::SineIn => "SineIn",
+            Easing::SineOut => "SineOut",
+            Easing::SineInOut => "SineInOut",
+            Easing::ExpoIn => "ExpoIn",
+            Easing::ExpoOut => "ExpoOut",
+            Easing::ExpoInOut => "ExpoInOut",
+            Easing::CircIn => "CircIn",
+            Easing::CircOut => "CircOut",
+            Easing::CircInOut => "CircInOut",
+            Easing::ElasticIn => "ElasticIn",
+            Easing::ElasticOut => "ElasticOut",
+            Easing::ElasticHalfOut => "ElasticHalfOut",
+            Easing::ElasticQuarterOut => "ElasticQuarterOut",
+            Easing::ElasticInOut => "ElasticInOut",
+            Easing::BackIn => "BackIn",
+            Easing::BackOut => "BackOut",
+            Easing::BackInOut => "BackInOut",
+            Easing::BounceIn => "BounceIn",
+            Easing::BounceOut => "BounceOut",
+            Easing::BounceInOut => "BounceInOut",
+        }
+    }
+
     /// Returns the `id` of an `Easing`
     ///
     /// Example:
@@ -141,6 +296,141 @@ impl Easing {
         self as u8
     }
 
+    /// Returns the [`EasingDirection`] of an `Easing`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Easing, EasingDirection};
+    /// assert_eq!(Easing::QuadInOut.direction(), EasingDirection::InOut);
+    /// assert_eq!(Easing::Out.direction(), EasingDirection::Out);
+    /// ```
+    pub fn direction(self) -> EasingDirection {
+        match self {
+            Easing::Linear => EasingDirection::Linear,
+            Easing::In
+            | Easing::QuadIn
+            | Easing::CubicIn
+            | Easing::QuartIn
+            | Easing::QuintIn
+            | Easing::SineIn
+            | Easing::ExpoIn
+            | Easing::CircIn
+            | Easing::ElasticIn
+            | Easing::BackIn
+            | Easing::BounceIn => EasingDirection::In,
+            Easing::Out
+            | Easing::QuadOut
+            | Easing::CubicOut
+            | Easing::QuartOut
+            | Easing::QuintOut
+            | Easing::SineOut
+            | Easing::ExpoOut
+            | Easing::CircOut
+            | Easing::ElasticOut
+            | Easing::ElasticHalfOut
+            | Easing::ElasticQuarterOut
+            | Easing::BackOut
+            | Easing::BounceOut => EasingDirection::Out,
+            Easing::QuadInOut
+            | Easing::CubicInOut
+            | Easing::QuartInOut
+            | Easing::QuintInOut
+            | Easing::SineInOut
+            | Easing::ExpoInOut
+            | Easing::CircInOut
+            | Easing::ElasticInOut
+            | Easing::BackInOut
+            | Easing::BounceInOut => EasingDirection::InOut,
+        }
+    }
+
+    /// Returns the [`EasingFamily`] of an `Easing`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Easing, EasingFamily};
+    /// assert_eq!(Easing::QuadInOut.family(), EasingFamily::Quad);
+    /// assert_eq!(Easing::Out.family(), EasingFamily::Quad);
+    /// ```
+    pub fn family(self) -> EasingFamily {
+        match self {
+            Easing::Linear => EasingFamily::Linear,
+            Easing::Out | Easing::In | Easing::QuadIn | Easing::QuadOut | Easing::QuadInOut => {
+                EasingFamily::Quad
+            }
+            Easing::CubicIn | Easing::CubicOut | Easing::CubicInOut => EasingFamily::Cubic,
+            Easing::QuartIn | Easing::QuartOut | Easing::QuartInOut => EasingFamily::Quart,
+            Easing::QuintIn | Easing::QuintOut | Easing::QuintInOut => EasingFamily::Quint,
+            Easing::SineIn | Easing::SineOut | Easing::SineInOut => EasingFamily::Sine,
+            Easing::ExpoIn | Easing::ExpoOut | Easing::ExpoInOut => EasingFamily::Expo,
+            Easing::CircIn | Easing::CircOut | Easing::CircInOut => EasingFamily::Circ,
+            Easing::ElasticIn
+            | Easing::ElasticOut
+            | Easing::ElasticHalfOut
+            | Easing::ElasticQuarterOut
+            | Easing::ElasticInOut => EasingFamily::Elastic,
+            Easing::BackIn | Easing::BackOut | Easing::BackInOut => EasingFamily::Back,
+            Easing::BounceIn | Easing::BounceOut | Easing::BounceInOut => EasingFamily::Bounce,
+        }
+    }
+
+    /// Returns `true` if the curve of an `Easing` looks the same when played backwards, which is
+    /// the case of `Linear` and `InOut` easings
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Easing;
+    /// assert!(Easing::QuadInOut.is_symmetric());
+    /// assert!(!Easing::QuadIn.is_symmetric());
+    /// ```
+    pub fn is_symmetric(self) -> bool {
+        match self.direction() {
+            EasingDirection::Linear | EasingDirection::InOut => true,
+            EasingDirection::In | EasingDirection::Out => false,
+        }
+    }
+
+    /// Returns the `Easing` describing the same curve played backwards
+    ///
+    /// `In` easings become `Out` easings and vice versa, while `Linear` and `InOut` easings are
+    /// left untouched since they are symmetric.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Easing;
+    /// assert_eq!(Easing::QuadIn.reversed(), Easing::QuadOut);
+    /// assert_eq!(Easing::SineInOut.reversed(), Easing::SineInOut);
+    /// ```
+    pub fn reversed(self) -> Easing {
+        match self {
+            Easing::Out => Easing::In,
+            Easing::In => Easing::Out,
+            Easing::QuadIn => Easing::QuadOut,
+            Easing::QuadOut => Easing::QuadIn,
+            Easing::CubicIn => Easing::CubicOut,
+            Easing::CubicOut => Easing::CubicIn,
+            Easing::QuartIn => Easing::QuartOut,
+            Easing::QuartOut => Easing::QuartIn,
+            Easing::QuintIn => Easing::QuintOut,
+            Easing::QuintOut => Easing::QuintIn,
+            Easing::SineIn => Easing::SineOut,
+            Easing::SineOut => Easing::SineIn,
+            Easing::ExpoIn => Easing::ExpoOut,
+            Easing::ExpoOut => Easing::ExpoIn,
+            Easing::CircIn => Easing::CircOut,
+            Easing::CircOut => Easing::CircIn,
+            Easing::ElasticIn => Easing::ElasticOut,
+            Easing::ElasticOut | Easing::ElasticHalfOut | Easing::ElasticQuarterOut => {
+                Easing::ElasticIn
+            }
+            Easing::BackIn => Easing::BackOut,
+            Easing::BackOut => Easing::BackIn,
+            Easing::BounceIn => Easing::BounceOut,
+            Easing::BounceOut => Easing::BounceIn,
+            easing => easing,
+        }
+    }
+
     /// Returns the value of an `Easing` at a certain time
     ///
     /// Example:
@@ -165,7 +455,7 @@ impl Easing {
         let from = from.into().as_f32();
         let to = to.into().as_f32();
 
-        if time < start_time || time > end_time || to < from {
+        if time < start_time || time > end_time {
             return None;
         }
 
@@ -176,6 +466,73 @@ impl Easing {
         )
     }
 
+    /// Returns the value of an `Easing` at a certain time for a transition between two [`Vec2`]s
+    ///
+    /// The easing is applied independently on both axes through [`Easing::ease`], `None` being
+    /// returned if `time` is out of the bounds of the transition.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Vec2, Easing};
+    ///
+    /// let value = Easing::Out.ease_vec2(1000, 0, 2000, Vec2::from(100, 0), Vec2::from(200, 40));
+    /// assert_eq!(value, Some(Vec2::from(175, 30)));
+    /// ```
+    pub fn ease_vec2(
+        self,
+        time: i32,
+        start_time: i32,
+        end_time: i32,
+        from: Vec2,
+        to: Vec2,
+    ) -> Option<Vec2> {
+        let x = self.ease(time, start_time, end_time, from.x, to.x)?;
+        let y = self.ease(time, start_time, end_time, from.y, to.y)?;
+        Some(Vec2::from(x, y).normalized())
+    }
+
+    /// Returns the value of an `Easing` at a certain time for a transition between two
+    /// [`Color`]s
+    ///
+    /// The easing is applied independently on each channel through [`Easing::ease`], alpha
+    /// included, the channels being rounded to the nearest integer and clamped like in
+    /// [`Color::from`]. `None` is returned if `time` is out of the bounds of the transition.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Color, Easing};
+    ///
+    /// let value = Easing::Linear.ease_color(500, 0, 1000, Color::black(), Color::white());
+    /// assert_eq!(value, Some(Color::from(128, 128, 128)));
+    /// ```
+    pub fn ease_color(
+        self,
+        time: i32,
+        start_time: i32,
+        end_time: i32,
+        from: Color,
+        to: Color,
+    ) -> Option<Color> {
+        let channel = |from: i32, to: i32| {
+            self.ease(time, start_time, end_time, from, to)
+                .map(|value| math::round(value) as i32)
+        };
+        Some(Color::from_rgba(
+            channel(from.r(), to.r())?,
+            channel(from.g(), to.g())?,
+            channel(from.b(), to.b())?,
+            channel(from.a(), to.a())?,
+        ))
+    }
+
+    // Eased progress, from 0 to 1, of a transition going from `start_time` to `end_time` at `time`
+    pub(crate) fn progress(self, time: i32, start_time: i32, end_time: i32) -> f32 {
+        if end_time <= start_time {
+            return if time < start_time { 0. } else { 1. };
+        }
+        self.calculate((time - start_time) as f32 / (end_time - start_time) as f32)
+    }
+
     fn calculate(self, x: f32) -> f32 {
         if x < f32::EPSILON {
             // if x < 0.
@@ -201,18 +558,18 @@ impl Easing {
             Easing::QuintIn => x * x * x * x * x,
             Easing::QuintOut => Easing::QuintIn.reverse(x),
             Easing::QuintInOut => Easing::QuintIn.in_out(x),
-            Easing::SineIn => 1. - (x * PI / 2.).cos(),
+            Easing::SineIn => 1. - math::cos(x * PI / 2.),
             Easing::SineOut => Easing::SineIn.reverse(x),
             Easing::SineInOut => Easing::SineIn.in_out(x),
-            Easing::ExpoIn => 2.0_f32.powf(10. * (x - 1.)),
+            Easing::ExpoIn => math::powf(2., 10. * (x - 1.)),
             Easing::ExpoOut => Easing::ExpoIn.reverse(x),
             Easing::ExpoInOut => Easing::ExpoIn.in_out(x),
-            Easing::CircIn => 1. - (1. - x * x).sqrt(),
+            Easing::CircIn => 1. - math::sqrt(1. - x * x),
             Easing::CircOut => Easing::CircIn.reverse(x),
             Easing::CircInOut => Easing::CircOut.in_out(x),
             Easing::ElasticIn => Easing::ElasticOut.reverse(x),
             Easing::ElasticOut | Easing::ElasticHalfOut | Easing::ElasticQuarterOut => {
-                2.0_f32.powf(-10. * x) * ((x - 0.075) * 2. * PI / 0.3).sin() + 1.
+                math::powf(2., -10. * x) * math::sin((x - 0.075) * 2. * PI / 0.3) + 1.
             }
             Easing::ElasticInOut => Easing::ElasticIn.in_out(x),
             Easing::BackIn => x * x * ((1.70158 + 1.) * x - 1.70158),
@@ -249,7 +606,64 @@ impl Easing {
 
 #[cfg(test)]
 mod tests {
-    use crate::Easing;
+    use super::EASINGS;
+    use crate::{
+        utils::{Color, Vec2},
+        Easing, EasingDirection, EasingFamily, EasingParsingError,
+    };
+
+    #[test]
+    fn ease_vec2() {
+        let from = Vec2::from(0, 100);
+        let to = Vec2::from(100, 300);
+        assert_eq!(
+            Easing::Linear.ease_vec2(250, 0, 1000, from, to),
+            Some(Vec2::from(25, 150))
+        );
+        assert_eq!(
+            Easing::QuadIn.ease_vec2(500, 0, 1000, from, to),
+            Some(Vec2::from(25, 150))
+        );
+        assert_eq!(Easing::Linear.ease_vec2(1001, 0, 1000, from, to), None);
+        assert_eq!(
+            Easing::Linear.ease_vec2(500, 0, 1000, from, Vec2::from(100, 0)),
+            Some(Vec2::from(50, 50))
+        );
+    }
+
+    #[test]
+    fn ease_color() {
+        let from = Color::from(0, 100, 200);
+        let to = Color::from(10, 200, 255);
+        assert_eq!(
+            Easing::Linear.ease_color(250, 0, 1000, from, to),
+            Some(Color::from(3, 125, 214))
+        );
+        assert_eq!(
+            Easing::Linear.ease_color(500, 0, 1000, Color::from_rgba(0, 0, 0, 0), Color::black()),
+            Some(Color::from_rgba(0, 0, 0, 128))
+        );
+        assert_eq!(Easing::Linear.ease_color(-1, 0, 1000, from, to), None);
+        assert_eq!(
+            Easing::Linear.ease_color(500, 0, 1000, to, from),
+            Some(Color::from(5, 150, 228))
+        );
+    }
+
+    #[test]
+    fn name() {
+        for (id, easing) in EASINGS.iter().enumerate() {
+            assert_eq!(easing.id() as usize, id);
+            assert_eq!(Easing::from_name(easing.name()).unwrap().id(), easing.id());
+            assert_eq!(format!("{:?}", easing), easing.name());
+        }
+        assert_eq!(Easing::from_name("elastichalfout").unwrap().id(), 26);
+        assert_eq!(Easing::from_name("LINEAR"), Ok(Easing::Linear));
+        assert_eq!(
+            Easing::from_name("Quad In"),
+            Err(EasingParsingError(String::from("Quad In")))
+        );
+    }
 
     #[test]
     fn get_easing() {
@@ -304,6 +718,20 @@ mod tests {
         assert_eq!(Easing::ElasticQuarterOut, Easing::ElasticHalfOut);
     }
 
+    #[test]
+    fn classification() {
+        assert_eq!(Easing::QuadInOut.direction(), EasingDirection::InOut);
+        assert_eq!(Easing::QuadInOut.family(), EasingFamily::Quad);
+        assert_eq!(Easing::Linear.direction(), EasingDirection::Linear);
+        assert_eq!(Easing::Linear.family(), EasingFamily::Linear);
+        assert_eq!(Easing::In.direction(), EasingDirection::In);
+        assert_eq!(Easing::ElasticQuarterOut.direction(), EasingDirection::Out);
+        assert_eq!(Easing::ElasticQuarterOut.family(), EasingFamily::Elastic);
+        assert_eq!(Easing::BounceIn.family(), EasingFamily::Bounce);
+        assert!(Easing::Linear.is_symmetric());
+        assert!(!Easing::BackOut.is_symmetric());
+    }
+
     #[test]
     fn ease_functions() {
         assert_eq!(Easing::CubicOut.ease(1, 0, 2, 0., 200.), Some(175.));
@@ -338,6 +766,25 @@ mod tests {
     #[test]
     fn ease_out_of_bounds() {
         assert_eq!(Easing::Linear.ease(5, 0, 4, 0., 10.), None);
-        assert_eq!(Easing::Linear.ease(2, 0, 4, 10., 5.), None);
+        assert_eq!(Easing::Linear.ease(-1, 0, 4, 10., 5.), None);
+    }
+
+    #[test]
+    fn ease_descending() {
+        assert_eq!(Easing::Linear.ease(500, 0, 1000, 200., 100.), Some(150.));
+        assert_eq!(Easing::Linear.ease(2, 0, 4, 10., 5.), Some(7.5));
+        assert_eq!(Easing::QuadIn.ease(500, 0, 1000, 1., 0.), Some(0.75));
+        assert_eq!(Easing::QuadIn.ease(1000, 0, 1000, 1., 0.), Some(0.));
+    }
+
+    #[test]
+    fn hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Easing::QuadOut);
+        assert!(!set.insert(Easing::QuadOut));
+        assert!(!set.insert(Easing::Out));
+        assert!(set.insert(Easing::ElasticHalfOut));
+        assert!(!set.insert(Easing::ElasticOut));
+        assert!(set.insert(Easing::QuadIn));
     }
 }
@@ -0,0 +1,147 @@
+use crate::{Layer, Origin};
+use alloc::string::String;
+use core::fmt;
+
+/// Error returned when merging two [`Sprite`](crate::Sprite)s that do not describe the same
+/// element
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpriteMismatch {
+    /// The paths of the `Sprite`s differ, holding the path of the `Sprite` merged into first
+    Path(String, String),
+    /// The [`Origin`]s of the `Sprite`s differ, holding the origin of the `Sprite` merged into
+    /// first
+    Origin(Origin, Origin),
+}
+
+impl fmt::Display for SpriteMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpriteMismatch::Path(expected, found) => {
+                write!(f, "sprite paths differ: \"{}\" and \"{}\"", expected, found)
+            }
+            SpriteMismatch::Origin(expected, found) => {
+                write!(f, "sprite origins differ: {} and {}", expected, found)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SpriteMismatch {}
+
+/// Error returned when appending a [`Module`](crate::Module) to a `Module` of another [`Layer`],
+/// holding the layer of the `Module` appended to first
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayerMismatch(pub Layer, pub Layer);
+
+impl fmt::Display for LayerMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "module layers differ: {} and {}", self.0, self.1)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LayerMismatch {}
+
+/// Error returned when converting a [`ScaleVec`](crate::event::ScaleVec) whose axes differ into a
+/// [`Scale`](crate::event::Scale)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaleMismatch;
+
+impl fmt::Display for ScaleMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "scale axes differ")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ScaleMismatch {}
+
+/// Error returned when no [`Easing`](crate::Easing) has the name given to
+/// [`Easing::from_name`](crate::Easing::from_name), holding that name
+#[derive(Clone, Debug, PartialEq)]
+pub struct EasingParsingError(pub String);
+
+impl fmt::Display for EasingParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown easing \"{}\"", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EasingParsingError {}
+
+/// Error returned when parsing a malformed [`Storyboard`](crate::Storyboard)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// The number of the malformed line, starting from `1`
+    pub line: usize,
+    /// What is wrong with the line
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// What is wrong with the line of a [`ParseError`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseErrorKind {
+    /// The line declares an unknown object or command, holding its name
+    UnknownCommand(String),
+    /// The line is a command outside of any sprite, or a nested command outside of any loop or
+    /// trigger
+    OrphanCommand,
+    /// The line lacks some of its fields
+    MissingField,
+    /// A field of the line is invalid, holding the field
+    InvalidField(String),
+    /// The line holds more values than a single event can, which only happens when parsing a
+    /// single event
+    TooManyFields,
+    /// The timestamps of the line go past the range of an `i32`, which happens with chained
+    /// values starting late enough
+    TimeOverflow,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnknownCommand(command) => write!(f, "unknown command \"{}\"", command),
+            ParseErrorKind::OrphanCommand => write!(f, "command outside of any sprite or group"),
+            ParseErrorKind::MissingField => write!(f, "missing field"),
+            ParseErrorKind::InvalidField(field) => write!(f, "invalid field \"{}\"", field),
+            ParseErrorKind::TooManyFields => write!(f, "too many fields"),
+            ParseErrorKind::TimeOverflow => write!(f, "timestamp out of range"),
+        }
+    }
+}
+
+/// Error returned when parsing a malformed hex color with
+/// [`Color::from_hex`](crate::utils::Color::from_hex)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorParseError {
+    /// The color does not have 6 hex digits, holding the number of digits found
+    InvalidLength(usize),
+    /// The color holds a character that is not a hex digit
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColorParseError::InvalidLength(len) => {
+                write!(f, "expected 6 hex digits, found {}", len)
+            }
+            ColorParseError::InvalidDigit(digit) => write!(f, "invalid hex digit '{}'", digit),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColorParseError {}
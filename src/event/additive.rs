@@ -1,15 +1,27 @@
 use crate::easing::Easing;
+use crate::event::stretch_time;
 use crate::Event;
+use alloc::{format, string::String};
 
 /// `Additive` event
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Additive {
+    Static(usize, i32),
     Dynamic(usize, Easing, i32, i32),
 }
 
 impl Event for Additive {
     fn to_line(&self) -> String {
         match self {
+            Additive::Static(depth, time) => {
+                format!(
+                    "{} P,{},{},,A",
+                    " ".repeat(*depth),
+                    Easing::Linear.id(),
+                    time
+                )
+            }
             Additive::Dynamic(depth, easing, start_time, end_time) => {
                 format!(
                     "{} P,{},{},{},A",
@@ -22,23 +34,111 @@ impl Event for Additive {
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "P"
+    }
+
+    fn parameter_suffix(&self) -> Option<char> {
+        Some('A')
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
-            Additive::Dynamic(ref mut current_depth, ..) => *current_depth = depth,
+            Additive::Static(ref mut current_depth, ..)
+            | Additive::Dynamic(ref mut current_depth, ..) => *current_depth = depth,
         }
     }
 
     fn get_start_time(&self) -> i32 {
         match self {
+            Additive::Static(_, time) => *time,
             Additive::Dynamic(_, _, start_time, _) => *start_time,
         }
     }
 
     fn get_end_time(&self) -> i32 {
         match self {
+            Additive::Static(_, time) => *time,
             Additive::Dynamic(_, _, _, end_time) => *end_time,
         }
     }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            Additive::Static(depth, time) => Additive::Static(*depth, total_duration - time),
+            Additive::Dynamic(depth, easing, start_time, end_time) => Additive::Dynamic(
+                *depth,
+                easing.reversed(),
+                total_duration - end_time,
+                total_duration - start_time,
+            ),
+        }
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            Additive::Static(depth, time) => Additive::Static(*depth, time + offset),
+            Additive::Dynamic(depth, easing, start_time, end_time) => {
+                Additive::Dynamic(*depth, *easing, start_time + offset, end_time + offset)
+            }
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            Additive::Static(depth, time) => {
+                Additive::Static(*depth, stretch_time(*time, factor, pivot))
+            }
+            Additive::Dynamic(depth, easing, start_time, end_time) => Additive::Dynamic(
+                *depth,
+                *easing,
+                stretch_time(*start_time, factor, pivot),
+                stretch_time(*end_time, factor, pivot),
+            ),
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            // A static flip stays on from its time onward, one set before `start` still applies
+            Additive::Static(_, time) if *time > end => None,
+            Additive::Static(depth, time) => Some(Additive::Static(*depth, (*time).max(start))),
+            Additive::Dynamic(_, _, start_time, end_time)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            Additive::Dynamic(depth, easing, start_time, end_time) => Some(Additive::Dynamic(
+                *depth,
+                *easing,
+                (*start_time).max(start),
+                (*end_time).min(end),
+            )),
+        }
+    }
+
+    fn rounded(&self, _decimals: usize) -> Self {
+        // Parameter events hold no values, there is nothing to round
+        self.clone()
+    }
+}
+
+/// Creates a static `Additive` event with the timestamp
+///
+/// Example:
+/// ```
+/// use osb::Sprite;
+///
+/// let time = 5000;
+///
+/// let mut sprite = Sprite::new("res/sprite.png");
+/// sprite.additive_(time);
+/// assert!(sprite.to_str().contains(" P,0,5000,,A\n"));
+/// ```
+impl Into<Additive> for i32 {
+    fn into(self) -> Additive {
+        Additive::Static(0, self)
+    }
 }
 
 /// Creates a `Additive` event with the timestamps
@@ -93,4 +193,26 @@ mod tests {
         additive_event_depth.set_depth(2);
         assert_eq!(additive_event_depth.to_line(), "   P,4,0,1000,A");
     }
+
+    #[test]
+    fn to_line_static() {
+        let mut additive_event: Additive = 5000.into();
+        assert_eq!(additive_event.to_line(), " P,0,5000,,A");
+        assert_eq!(additive_event.get_start_time(), 5000);
+        assert_eq!(additive_event.get_end_time(), 5000);
+
+        additive_event.set_depth(1);
+        assert_eq!(additive_event.to_line(), "  P,0,5000,,A");
+        assert_eq!(additive_event.reversed(6000).to_line(), "  P,0,1000,,A");
+        assert_eq!(additive_event.shifted(-1000).to_line(), "  P,0,4000,,A");
+        assert!(additive_event.clip(0, 4999).is_none());
+        assert_eq!(
+            additive_event.clip(6000, 7000).unwrap().to_line(),
+            "  P,0,6000,,A"
+        );
+        assert_eq!(
+            additive_event.clip(5000, 7000).unwrap().to_line(),
+            "  P,0,5000,,A"
+        );
+    }
 }
@@ -0,0 +1,190 @@
+use crate::event::*;
+use alloc::string::String;
+use core::convert::TryFrom;
+
+/// Any of the concrete `Event`s
+///
+/// `AnyEvent` gives a single type to match on, which comes handy when bridging with other crates
+/// of the osu! ecosystem. Every concrete event converts into an `AnyEvent` with `From`, and back
+/// with `TryFrom`, the `AnyEvent` being given back if it wraps another kind of event.
+///
+/// Example:
+/// ```
+/// use osb::event::{AnyEvent, Event, Move};
+/// use std::convert::TryFrom;
+///
+/// let move_: Move = (0, 1000, 0, 0, 320, 240).into();
+/// let event = AnyEvent::from(move_);
+/// assert_eq!(event.to_line(), " M,0,0,1000,0,0,320,240");
+///
+/// let move_ = Move::try_from(event).ok().unwrap();
+/// assert_eq!(move_.to_line(), " M,0,0,1000,0,0,320,240");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnyEvent {
+    Move(Move),
+    MoveX(MoveX),
+    MoveY(MoveY),
+    Fade(Fade),
+    Rotate(Rotate),
+    Scale(Scale),
+    ScaleVec(ScaleVec),
+    Color(Color),
+    HFlip(HFlip),
+    VFlip(VFlip),
+    Additive(Additive),
+}
+
+// Evaluates `$body` with `$event` bound to the concrete event wrapped by `$any`, wrapping the result
+// back into the same kind of `AnyEvent` when prefixed with `wrap`
+macro_rules! dispatch {
+    ($any:expr, $event:ident => $body:expr) => {
+        match $any {
+            AnyEvent::Move($event) => $body,
+            AnyEvent::MoveX($event) => $body,
+            AnyEvent::MoveY($event) => $body,
+            AnyEvent::Fade($event) => $body,
+            AnyEvent::Rotate($event) => $body,
+            AnyEvent::Scale($event) => $body,
+            AnyEvent::ScaleVec($event) => $body,
+            AnyEvent::Color($event) => $body,
+            AnyEvent::HFlip($event) => $body,
+            AnyEvent::VFlip($event) => $body,
+            AnyEvent::Additive($event) => $body,
+        }
+    };
+    ($any:expr, $event:ident => wrap $body:expr) => {
+        match $any {
+            AnyEvent::Move($event) => AnyEvent::Move($body),
+            AnyEvent::MoveX($event) => AnyEvent::MoveX($body),
+            AnyEvent::MoveY($event) => AnyEvent::MoveY($body),
+            AnyEvent::Fade($event) => AnyEvent::Fade($body),
+            AnyEvent::Rotate($event) => AnyEvent::Rotate($body),
+            AnyEvent::Scale($event) => AnyEvent::Scale($body),
+            AnyEvent::ScaleVec($event) => AnyEvent::ScaleVec($body),
+            AnyEvent::Color($event) => AnyEvent::Color($body),
+            AnyEvent::HFlip($event) => AnyEvent::HFlip($body),
+            AnyEvent::VFlip($event) => AnyEvent::VFlip($body),
+            AnyEvent::Additive($event) => AnyEvent::Additive($body),
+        }
+    };
+}
+
+impl Event for AnyEvent {
+    fn to_line(&self) -> String {
+        dispatch!(self, event => event.to_line())
+    }
+
+    fn to_line_with_precision(&self, decimals: usize) -> String {
+        dispatch!(self, event => event.to_line_with_precision(decimals))
+    }
+
+    fn command_char(&self) -> &'static str {
+        dispatch!(self, event => event.command_char())
+    }
+
+    fn parameter_suffix(&self) -> Option<char> {
+        dispatch!(self, event => event.parameter_suffix())
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        dispatch!(self, event => event.set_depth(depth))
+    }
+
+    fn get_start_time(&self) -> i32 {
+        dispatch!(self, event => event.get_start_time())
+    }
+
+    fn get_end_time(&self) -> i32 {
+        dispatch!(self, event => event.get_end_time())
+    }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        dispatch!(self, event => wrap event.reversed(total_duration))
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        Some(dispatch!(self, event => wrap event.clip(start, end)?))
+    }
+
+    fn rounded(&self, decimals: usize) -> Self {
+        dispatch!(self, event => wrap event.rounded(decimals))
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        dispatch!(self, event => wrap event.shifted(offset))
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        dispatch!(self, event => wrap event.stretched(factor, pivot))
+    }
+}
+
+// Converts a concrete event into an `AnyEvent` and back
+macro_rules! any_event_conversions {
+    ($($kind:ident),*) => {
+        $(
+            impl From<$kind> for AnyEvent {
+                fn from(event: $kind) -> AnyEvent {
+                    AnyEvent::$kind(event)
+                }
+            }
+
+            impl TryFrom<AnyEvent> for $kind {
+                type Error = AnyEvent;
+
+                fn try_from(event: AnyEvent) -> Result<$kind, AnyEvent> {
+                    match event {
+                        AnyEvent::$kind(event) => Ok(event),
+                        event => Err(event),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+any_event_conversions!(
+    Move, MoveX, MoveY, Fade, Rotate, Scale, ScaleVec, Color, HFlip, VFlip, Additive
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{event::*, Easing};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn move_round_trip() {
+        let move_: Move = (Easing::QuadOut, 0, 1000, 0, 0, 320, 240).into();
+        let line = move_.to_line();
+
+        let event = AnyEvent::from(move_);
+        assert_eq!(event.to_line(), line);
+        assert_eq!(event.get_start_time(), 0);
+        assert_eq!(event.get_end_time(), 1000);
+        assert_eq!(event.command_char(), "M");
+
+        let move_ = Move::try_from(event).ok().unwrap();
+        assert_eq!(move_.to_line(), line);
+    }
+
+    #[test]
+    fn wrong_kind() {
+        let event = AnyEvent::from(Into::<Fade>::into((0, 1000, 0, 1)));
+        let event = Move::try_from(event).err().unwrap();
+        assert_eq!(event.to_line(), " F,0,0,1000,0,1");
+    }
+
+    #[test]
+    fn event_methods() {
+        let mut event = AnyEvent::from(Into::<Scale>::into((0, 1000, 1, 2)));
+        event.set_depth(1);
+        assert_eq!(event.to_line(), "  S,0,0,1000,1,2");
+        assert_eq!(event.shifted(500).to_line(), "  S,0,500,1500,1,2");
+        assert_eq!(event.reversed(1000).to_line(), "  S,0,0,1000,2,1");
+        assert_eq!(event.truncated(500).unwrap().to_line(), "  S,0,0,500,1,1.5");
+        assert!(event.shifted(1000).truncated(500).is_none());
+        assert_eq!(event.stretched(1.5, 500).to_line(), "  S,0,-250,1250,1,2");
+    }
+}
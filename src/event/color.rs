@@ -1,12 +1,75 @@
 use crate::easing::Easing;
+use crate::event::stretch_time;
 use crate::utils;
 use crate::Event;
+use alloc::vec::Vec;
+use alloc::{format, string::String};
 
 /// `Color` event
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
     Static(usize, i32, utils::Color),
     Dynamic(usize, Easing, i32, i32, utils::Color, utils::Color),
+    /// Transitions through each of the colors in turn, every transition lasting as long as the
+    /// first one, which goes from the first timestamp to the second one
+    Chained(usize, Easing, i32, i32, Vec<utils::Color>),
+}
+
+impl Color {
+    /// Returns the color set by the `Color` event at the timestamp `time`
+    ///
+    /// Before the event starts, its starting color is returned, and its ending color after it
+    /// ends.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{event::Color, utils};
+    ///
+    /// let event: Color = (0, 1000, utils::Color::black(), utils::Color::white()).into();
+    /// assert_eq!(event.value_at(500), utils::Color::from(128, 128, 128));
+    /// ```
+    pub fn value_at(&self, time: i32) -> utils::Color {
+        match self {
+            Color::Static(_, _, value) => *value,
+            Color::Dynamic(_, easing, start_time, end_time, start_value, end_value) => {
+                start_value.interpolate(*end_value, easing.progress(time, *start_time, *end_time))
+            }
+            Color::Chained(_, easing, start_time, end_time, colors) => {
+                let step = end_time - start_time;
+                let last = colors.len().saturating_sub(1);
+                let index = if time < *start_time {
+                    0
+                } else if step <= 0 {
+                    last
+                } else {
+                    (((time - start_time) / step) as usize).min(last)
+                };
+                match (colors.get(index), colors.get(index + 1)) {
+                    (Some(from), Some(to)) => {
+                        let start_time = start_time + step * index as i32;
+                        from.interpolate(*to, easing.progress(time, start_time, start_time + step))
+                    }
+                    (Some(color), None) => *color,
+                    (None, _) => utils::Color::white(),
+                }
+            }
+        }
+    }
+
+    // Timestamp at which the last transition of a chained `Color` ends
+    fn chain_end_time(start_time: i32, end_time: i32, colors: &[utils::Color]) -> i32 {
+        start_time + (end_time - start_time) * (colors.len() as i32 - 1).max(1)
+    }
+}
+
+// Greatest common divisor of two non-negative numbers
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
 }
 
 impl Event for Color {
@@ -38,13 +101,32 @@ impl Event for Color {
                     end_color.b(),
                 )
             }
+            Color::Chained(depth, easing, start_time, end_time, colors) => {
+                format!(
+                    "{} C,{},{},{},{}",
+                    " ".repeat(*depth),
+                    easing.id(),
+                    start_time,
+                    end_time,
+                    colors
+                        .iter()
+                        .map(|color| format!("{},{},{}", color.r(), color.g(), color.b()))
+                        .collect::<Vec<String>>()
+                        .join(","),
+                )
+            }
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "C"
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
             Color::Static(ref mut current_depth, ..) => *current_depth = depth,
             Color::Dynamic(ref mut current_depth, ..) => *current_depth = depth,
+            Color::Chained(ref mut current_depth, ..) => *current_depth = depth,
         }
     }
 
@@ -52,6 +134,7 @@ impl Event for Color {
         match self {
             Color::Static(_, start_time, _) => *start_time,
             Color::Dynamic(_, _, start_time, ..) => *start_time,
+            Color::Chained(_, _, start_time, ..) => *start_time,
         }
     }
 
@@ -59,8 +142,160 @@ impl Event for Color {
         match self {
             Color::Static(_, end_time, _) => *end_time,
             Color::Dynamic(_, _, _, end_time, ..) => *end_time,
+            Color::Chained(_, _, start_time, end_time, colors) => {
+                Color::chain_end_time(*start_time, *end_time, colors)
+            }
+        }
+    }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            Color::Static(depth, time, value) => {
+                Color::Static(*depth, total_duration - time, *value)
+            }
+            Color::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Color::Dynamic(
+                    *depth,
+                    easing.reversed(),
+                    total_duration - end_time,
+                    total_duration - start_time,
+                    *end_value,
+                    *start_value,
+                )
+            }
+            Color::Chained(depth, easing, start_time, end_time, colors) => {
+                let chain_end_time = Color::chain_end_time(*start_time, *end_time, colors);
+                Color::Chained(
+                    *depth,
+                    easing.reversed(),
+                    total_duration - chain_end_time,
+                    total_duration - chain_end_time + end_time - start_time,
+                    colors.iter().rev().copied().collect(),
+                )
+            }
         }
     }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            Color::Static(depth, time, value) => Color::Static(*depth, time + offset, *value),
+            Color::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Color::Dynamic(
+                    *depth,
+                    *easing,
+                    start_time + offset,
+                    end_time + offset,
+                    *start_value,
+                    *end_value,
+                )
+            }
+            Color::Chained(depth, easing, start_time, end_time, colors) => Color::Chained(
+                *depth,
+                *easing,
+                start_time + offset,
+                end_time + offset,
+                colors.clone(),
+            ),
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            Color::Static(depth, time, value) => {
+                Color::Static(*depth, stretch_time(*time, factor, pivot), *value)
+            }
+            Color::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Color::Dynamic(
+                    *depth,
+                    *easing,
+                    stretch_time(*start_time, factor, pivot),
+                    stretch_time(*end_time, factor, pivot),
+                    *start_value,
+                    *end_value,
+                )
+            }
+            Color::Chained(depth, easing, start_time, end_time, colors) => Color::Chained(
+                *depth,
+                *easing,
+                stretch_time(*start_time, factor, pivot),
+                stretch_time(*end_time, factor, pivot),
+                colors.clone(),
+            ),
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            Color::Static(_, time, _) if *time < start || *time > end => None,
+            Color::Dynamic(_, _, start_time, end_time, ..)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            Color::Chained(_, _, start_time, ..)
+                if self.get_end_time() < start || *start_time > end =>
+            {
+                None
+            }
+            Color::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                let (clipped_start, clipped_end) = ((*start_time).max(start), (*end_time).min(end));
+                Some(Color::Dynamic(
+                    *depth,
+                    *easing,
+                    clipped_start,
+                    clipped_end,
+                    if clipped_start == *start_time {
+                        *start_value
+                    } else {
+                        self.value_at(clipped_start)
+                    },
+                    if clipped_end == *end_time {
+                        *end_value
+                    } else {
+                        self.value_at(clipped_end)
+                    },
+                ))
+            }
+            // Transitions partly within the range are cut at its edges. The transitions of a
+            // chain all lasting as long, it is split on the coarsest grid keeping both edges and
+            // every color in between in place, each piece keeping the easing
+            Color::Chained(depth, easing, start_time, end_time, _) if *end_time > *start_time => {
+                let (clipped_start, clipped_end) =
+                    ((*start_time).max(start), self.get_end_time().min(end));
+                let step = gcd(
+                    gcd(end_time - start_time, clipped_start - start_time),
+                    clipped_end - start_time,
+                );
+                let pieces = (clipped_end - clipped_start) / step;
+                if pieces < 2 {
+                    Some(Color::Dynamic(
+                        *depth,
+                        *easing,
+                        clipped_start,
+                        clipped_end,
+                        self.value_at(clipped_start),
+                        self.value_at(clipped_end),
+                    ))
+                } else {
+                    Some(Color::Chained(
+                        *depth,
+                        *easing,
+                        clipped_start,
+                        clipped_start + step,
+                        (0..=pieces)
+                            .map(|i| self.value_at(clipped_start + step * i))
+                            .collect(),
+                    ))
+                }
+            }
+            event => Some(event.clone()),
+        }
+    }
+
+    fn rounded(&self, _decimals: usize) -> Self {
+        // Color channels are integers, there is nothing to round
+        self.clone()
+    }
 }
 
 /// Creates a static `Color` event with the timestamp and the colorization of the element
@@ -215,7 +450,7 @@ impl Into<Color> for (Easing, i32, i32, i32, i32, i32, i32, i32, i32) {
 
 #[cfg(test)]
 mod tests {
-    use crate::{event::*, Easing};
+    use crate::{event::*, utils, Easing};
 
     #[test]
     fn to_line_static() {
@@ -232,4 +467,55 @@ mod tests {
         let color_event_easing: Color = (Easing::QuadOut, 0, 1000, 0, 0, 0, 255, 255, 255).into();
         assert_eq!(color_event_easing.to_line(), " C,4,0,1000,0,0,0,255,255,255");
     }
+
+    #[test]
+    fn to_line_chained() {
+        let colors = vec![
+            utils::Color::red(),
+            utils::Color::green(),
+            utils::Color::blue(),
+        ];
+        let color_event = Color::Chained(0, Easing::QuadOut, 1000, 1500, colors);
+        assert_eq!(
+            color_event.to_line(),
+            " C,4,1000,1500,255,0,0,0,255,0,0,0,255"
+        );
+        assert_eq!(color_event.get_end_time(), 2000);
+
+        assert_eq!(
+            color_event.reversed(3000).to_line(),
+            " C,3,1000,1500,0,0,255,0,255,0,255,0,0"
+        );
+    }
+
+    #[test]
+    fn clip_chained() {
+        let colors = vec![
+            utils::Color::red(),
+            utils::Color::green(),
+            utils::Color::blue(),
+            utils::Color::white(),
+        ];
+        let color_event = Color::Chained(0, Easing::Linear, 0, 500, colors);
+        assert_eq!(
+            color_event.clip(500, 1500).unwrap().to_line(),
+            " C,0,500,1000,0,255,0,0,0,255,255,255,255"
+        );
+        // Cut through the middle of the first and of the last transitions
+        assert_eq!(
+            color_event.clip(250, 1250).unwrap().to_line(),
+            " C,0,250,500,128,128,0,0,255,0,0,128,128,0,0,255,128,128,255"
+        );
+        let clipped = color_event.clip(400, 1600).unwrap();
+        assert_eq!(clipped.get_start_time(), 400);
+        assert_eq!(clipped.get_end_time(), 1500);
+        for time in [400, 450, 500, 1000, 1234, 1500] {
+            assert_eq!(clipped.value_at(time), color_event.value_at(time));
+        }
+        assert_eq!(
+            color_event.clip(250, 500).unwrap().to_line(),
+            " C,0,250,500,128,128,0,0,255,0"
+        );
+        assert!(color_event.clip(1501, 2000).is_none());
+    }
 }
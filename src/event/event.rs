@@ -1,7 +1,85 @@
+use alloc::string::String;
+
 /// Trait defining `Event`s
 pub trait Event {
+    /// Returns the `Event` as a line of the .osb file
+    ///
+    /// Static events hold no easing, their easing field is therefore always the `Linear` id `0`.
+    /// Floating values are rounded to [`DEFAULT_PRECISION`](crate::utils::DEFAULT_PRECISION)
+    /// decimal places.
     fn to_line(&self) -> String;
+
+    /// Returns the `Event` as a line of the .osb file, its floating values being rounded to
+    /// `decimals` decimal places
+    ///
+    /// Events holding no floating value write the same line as [`Event::to_line`].
+    fn to_line_with_precision(&self, _decimals: usize) -> String {
+        self.to_line()
+    }
+
+    /// Returns the command token starting the line of the `Event`, such as `M` for a `Move` or `P`
+    /// for the parameter events `HFlip`, `VFlip` and `Additive`
+    fn command_char(&self) -> &'static str;
+
+    /// Returns the letter telling parameter events apart, `H`, `V` or `A`, or `None` if the
+    /// `Event` isn't a parameter event
+    fn parameter_suffix(&self) -> Option<char> {
+        None
+    }
+
     fn set_depth(&mut self, depth: usize);
     fn get_start_time(&self) -> i32;
     fn get_end_time(&self) -> i32;
+
+    /// Returns the `Event` as it would play in a storyboard of length `total_duration` played
+    /// backwards: timestamps are mirrored, start and end values are swapped and the easing is
+    /// reversed
+    fn reversed(&self, total_duration: i32) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the `Event` restricted to the time range going from `start` to `end`, or `None`
+    /// if it lies entirely outside of it
+    ///
+    /// A dynamic `Event` clipped while in progress keeps its easing, its new endpoints taking the
+    /// values the `Event` had at these timestamps. A static flip staying on from its time onward,
+    /// one set before `start` is kept and moved to `start`.
+    fn clip(&self, start: i32, end: i32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns the `Event` cut so that it ends at the timestamp `end` at the latest, or `None` if
+    /// it starts after `end`
+    ///
+    /// See [`Event::clip`] for more details.
+    fn truncated(&self, end: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.clip(i32::MIN, end)
+    }
+
+    /// Returns the `Event` with each of its values rounded to `decimals` decimal places
+    fn rounded(&self, decimals: usize) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the `Event` with every timestamp moved by `offset`
+    fn shifted(&self, offset: i32) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the `Event` with every timestamp `t` moved to `pivot + (t - pivot) * factor`,
+    /// truncated to the millisecond, stretching it around `pivot` when `factor` is greater than 1
+    /// and compressing it otherwise
+    ///
+    /// `factor` is expected to be positive, so that the `Event` still ends after it starts.
+    fn stretched(&self, factor: f32, pivot: i32) -> Self
+    where
+        Self: Sized;
+}
+
+// Maps the timestamp `time` as described by `Event::stretched`
+pub(crate) fn stretch_time(time: i32, factor: f32, pivot: i32) -> i32 {
+    pivot + ((time - pivot) as f32 * factor) as i32
 }
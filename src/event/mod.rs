@@ -1,4 +1,5 @@
 mod additive;
+mod any;
 mod color;
 mod event;
 mod fade;
@@ -12,7 +13,9 @@ mod scalevec;
 mod vflip;
 
 pub use additive::*;
+pub use any::*;
 pub use color::*;
+pub(crate) use event::stretch_time;
 pub use event::Event;
 pub use fade::*;
 pub use hflip::*;
@@ -23,3 +26,83 @@ pub use rotate::*;
 pub use scale::*;
 pub use scalevec::*;
 pub use vflip::*;
+
+use core::fmt;
+
+// Displays each event as its line of the .osb file
+macro_rules! event_display {
+    ($($kind:ident),*) => {
+        $(
+            impl fmt::Display for $kind {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{}", self.to_line())
+                }
+            }
+        )*
+    };
+}
+
+event_display!(
+    Move, MoveX, MoveY, Fade, Rotate, Scale, ScaleVec, Color, HFlip, VFlip, Additive, AnyEvent
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{event::*, utils};
+
+    #[test]
+    fn static_easing_is_linear() {
+        let lines = [
+            Into::<Move>::into((1000, 320, 240)).to_line(),
+            Into::<MoveX>::into((1000, 320)).to_line(),
+            Into::<MoveY>::into((1000, 240)).to_line(),
+            Into::<Fade>::into((1000, 0.5)).to_line(),
+            Into::<Rotate>::into((1000, 1.5)).to_line(),
+            Into::<Scale>::into((1000, 2)).to_line(),
+            Into::<ScaleVec>::into((1000, 2, 3)).to_line(),
+            Into::<Color>::into((1000, utils::Color::red())).to_line(),
+        ];
+        for line in &lines {
+            assert_eq!(line.split(',').nth(1), Some("0"), "{}", line);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let move_: Move = (0, 1000, 0, 0, 320, 240).into();
+        assert_eq!(format!("{}", move_), move_.to_line());
+        assert_eq!(
+            format!("{}", AnyEvent::from(move_)),
+            " M,0,0,1000,0,0,320,240"
+        );
+
+        let mut fade: Fade = (1000, 0.5).into();
+        fade.set_depth(1);
+        assert_eq!(fade.to_string(), "  F,0,1000,,0.5");
+        assert_eq!(format!("{:?}", fade), "Static(1, 1000, Float(0.5))");
+    }
+
+    #[test]
+    fn command_char() {
+        let move_: Move = (1000, 320, 240).into();
+        assert_eq!(move_.command_char(), "M");
+        assert_eq!(move_.parameter_suffix(), None);
+
+        let hflip: HFlip = (0, 1000).into();
+        assert_eq!(hflip.command_char(), "P");
+        assert_eq!(hflip.parameter_suffix(), Some('H'));
+
+        let events: [&dyn Event; 3] = [
+            &Into::<VFlip>::into((0, 1000)),
+            &Into::<Additive>::into((0, 1000)),
+            &Into::<ScaleVec>::into((1000, 2, 3)),
+        ];
+        for event in &events {
+            let line = event.to_line();
+            assert_eq!(
+                line.trim_start().split(',').next(),
+                Some(event.command_char())
+            );
+        }
+    }
+}
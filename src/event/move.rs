@@ -1,20 +1,50 @@
 use crate::easing::Easing;
-use crate::utils::{Number, Vec2};
+use crate::event::stretch_time;
+use crate::utils::{Number, Vec2, DEFAULT_PRECISION};
 use crate::Event;
+use alloc::{format, string::String};
 
 /// `Move` event
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Move {
     Static(usize, i32, Vec2),
     Dynamic(usize, Easing, i32, i32, Vec2, Vec2),
 }
 
+impl Move {
+    /// Returns the position set by the `Move` event at the timestamp `time`
+    ///
+    /// Before the event starts, its starting position is returned, and its ending position after it
+    /// ends.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{event::Move, utils::Vec2};
+    ///
+    /// let event: Move = (0, 1000, 0, 0, 100, 200).into();
+    /// assert_eq!(event.value_at(500), Vec2::from(50, 100));
+    /// ```
+    pub fn value_at(&self, time: i32) -> Vec2 {
+        match self {
+            Move::Static(_, _, value) => *value,
+            Move::Dynamic(_, easing, start_time, end_time, start_value, end_value) => {
+                start_value.lerp(*end_value, easing.progress(time, *start_time, *end_time))
+            }
+        }
+    }
+}
+
 impl Event for Move {
     fn to_line(&self) -> String {
+        self.to_line_with_precision(DEFAULT_PRECISION)
+    }
+
+    fn to_line_with_precision(&self, decimals: usize) -> String {
         match self {
             Move::Static(depth, time, pos) => {
                 format!(
-                    "{} M,{},{},,{},{}",
+                    "{} M,{},{},,{:.decimals$},{:.decimals$}",
                     " ".repeat(*depth),
                     Easing::Linear.id(),
                     time,
@@ -24,7 +54,7 @@ impl Event for Move {
             }
             Move::Dynamic(depth, easing, start_time, end_time, start_pos, end_pos) => {
                 format!(
-                    "{} M,{},{},{},{},{},{},{}",
+                    "{} M,{},{},{},{:.decimals$},{:.decimals$},{:.decimals$},{:.decimals$}",
                     " ".repeat(*depth),
                     easing.id(),
                     start_time,
@@ -38,6 +68,10 @@ impl Event for Move {
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "M"
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
             Move::Static(ref mut current_depth, ..) => *current_depth = depth,
@@ -58,6 +92,105 @@ impl Event for Move {
             Move::Dynamic(_, _, _, end_time, ..) => *end_time,
         }
     }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            Move::Static(depth, time, value) => Move::Static(*depth, total_duration - time, *value),
+            Move::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Move::Dynamic(
+                    *depth,
+                    easing.reversed(),
+                    total_duration - end_time,
+                    total_duration - start_time,
+                    *end_value,
+                    *start_value,
+                )
+            }
+        }
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            Move::Static(depth, time, value) => Move::Static(*depth, time + offset, *value),
+            Move::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Move::Dynamic(
+                    *depth,
+                    *easing,
+                    start_time + offset,
+                    end_time + offset,
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            Move::Static(depth, time, value) => {
+                Move::Static(*depth, stretch_time(*time, factor, pivot), *value)
+            }
+            Move::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Move::Dynamic(
+                    *depth,
+                    *easing,
+                    stretch_time(*start_time, factor, pivot),
+                    stretch_time(*end_time, factor, pivot),
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            Move::Static(_, time, _) if *time < start || *time > end => None,
+            Move::Dynamic(_, _, start_time, end_time, ..)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            Move::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                let (clipped_start, clipped_end) = ((*start_time).max(start), (*end_time).min(end));
+                Some(Move::Dynamic(
+                    *depth,
+                    *easing,
+                    clipped_start,
+                    clipped_end,
+                    if clipped_start == *start_time {
+                        *start_value
+                    } else {
+                        self.value_at(clipped_start)
+                    },
+                    if clipped_end == *end_time {
+                        *end_value
+                    } else {
+                        self.value_at(clipped_end)
+                    },
+                ))
+            }
+            event => Some(event.clone()),
+        }
+    }
+
+    fn rounded(&self, decimals: usize) -> Self {
+        match self {
+            Move::Static(depth, time, value) => {
+                Move::Static(*depth, *time, value.rounded(decimals))
+            }
+            Move::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Move::Dynamic(
+                    *depth,
+                    *easing,
+                    *start_time,
+                    *end_time,
+                    start_value.rounded(decimals),
+                    end_value.rounded(decimals),
+                )
+            }
+        }
+    }
 }
 
 /// Creates a static `Move` event with the timestamp and the position of the element
@@ -246,4 +379,24 @@ mod tests {
         let move_event_easing: Move = (Easing::QuadOut, 0, 1000, 0, 0, 320, 240).into();
         assert_eq!(move_event_easing.to_line(), " M,4,0,1000,0,0,320,240");
     }
+
+    #[test]
+    fn clip() {
+        let move_event: Move = (0, 1000, 0, 0, 100, 200).into();
+        let clipped = move_event.clip(250, 750).unwrap();
+        assert_eq!(clipped.to_line(), " M,0,250,750,25,50,75,150");
+        assert_eq!(
+            move_event.clip(-500, 500).unwrap().to_line(),
+            " M,0,0,500,0,0,50,100"
+        );
+        assert!(move_event.clip(1001, 2000).is_none());
+        assert!(move_event.clip(-1000, -1).is_none());
+
+        let move_event_static: Move = (500, 320, 240).into();
+        assert_eq!(
+            move_event_static.clip(0, 1000).unwrap().to_line(),
+            " M,0,500,,320,240"
+        );
+        assert!(move_event_static.clip(0, 499).is_none());
+    }
 }
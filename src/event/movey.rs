@@ -1,20 +1,50 @@
 use crate::easing::Easing;
-use crate::utils::Number;
+use crate::event::stretch_time;
+use crate::utils::{Number, DEFAULT_PRECISION};
 use crate::Event;
+use alloc::{format, string::String};
 
 /// `MoveY` event
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveY {
     Static(usize, i32, Number),
     Dynamic(usize, Easing, i32, i32, Number, Number),
 }
 
+impl MoveY {
+    /// Returns the Y position set by the `MoveY` event at the timestamp `time`
+    ///
+    /// Before the event starts, its starting Y position is returned, and its ending Y position after it
+    /// ends.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{event::MoveY, utils::Number};
+    ///
+    /// let event: MoveY = (0, 1000, 0, 100).into();
+    /// assert_eq!(event.value_at(500), Number::Int(50));
+    /// ```
+    pub fn value_at(&self, time: i32) -> Number {
+        match self {
+            MoveY::Static(_, _, value) => *value,
+            MoveY::Dynamic(_, easing, start_time, end_time, start_value, end_value) => {
+                start_value.lerp(*end_value, easing.progress(time, *start_time, *end_time))
+            }
+        }
+    }
+}
+
 impl Event for MoveY {
     fn to_line(&self) -> String {
+        self.to_line_with_precision(DEFAULT_PRECISION)
+    }
+
+    fn to_line_with_precision(&self, decimals: usize) -> String {
         match self {
             MoveY::Static(depth, time, value) => {
                 format!(
-                    "{} MY,{},{},,{}",
+                    "{} MY,{},{},,{:.decimals$}",
                     " ".repeat(*depth),
                     Easing::Linear.id(),
                     time,
@@ -23,7 +53,7 @@ impl Event for MoveY {
             }
             MoveY::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
                 format!(
-                    "{} MY,{},{},{},{},{}",
+                    "{} MY,{},{},{},{:.decimals$},{:.decimals$}",
                     " ".repeat(*depth),
                     easing.id(),
                     start_time,
@@ -35,6 +65,10 @@ impl Event for MoveY {
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "MY"
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
             MoveY::Static(ref mut current_depth, ..) => *current_depth = depth,
@@ -55,6 +89,107 @@ impl Event for MoveY {
             MoveY::Dynamic(_, _, _, end_time, ..) => *end_time,
         }
     }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            MoveY::Static(depth, time, value) => {
+                MoveY::Static(*depth, total_duration - time, *value)
+            }
+            MoveY::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                MoveY::Dynamic(
+                    *depth,
+                    easing.reversed(),
+                    total_duration - end_time,
+                    total_duration - start_time,
+                    *end_value,
+                    *start_value,
+                )
+            }
+        }
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            MoveY::Static(depth, time, value) => MoveY::Static(*depth, time + offset, *value),
+            MoveY::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                MoveY::Dynamic(
+                    *depth,
+                    *easing,
+                    start_time + offset,
+                    end_time + offset,
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            MoveY::Static(depth, time, value) => {
+                MoveY::Static(*depth, stretch_time(*time, factor, pivot), *value)
+            }
+            MoveY::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                MoveY::Dynamic(
+                    *depth,
+                    *easing,
+                    stretch_time(*start_time, factor, pivot),
+                    stretch_time(*end_time, factor, pivot),
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            MoveY::Static(_, time, _) if *time < start || *time > end => None,
+            MoveY::Dynamic(_, _, start_time, end_time, ..)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            MoveY::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                let (clipped_start, clipped_end) = ((*start_time).max(start), (*end_time).min(end));
+                Some(MoveY::Dynamic(
+                    *depth,
+                    *easing,
+                    clipped_start,
+                    clipped_end,
+                    if clipped_start == *start_time {
+                        *start_value
+                    } else {
+                        self.value_at(clipped_start)
+                    },
+                    if clipped_end == *end_time {
+                        *end_value
+                    } else {
+                        self.value_at(clipped_end)
+                    },
+                ))
+            }
+            event => Some(event.clone()),
+        }
+    }
+
+    fn rounded(&self, decimals: usize) -> Self {
+        match self {
+            MoveY::Static(depth, time, value) => {
+                MoveY::Static(*depth, *time, value.rounded(decimals))
+            }
+            MoveY::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                MoveY::Dynamic(
+                    *depth,
+                    *easing,
+                    *start_time,
+                    *end_time,
+                    start_value.rounded(decimals),
+                    end_value.rounded(decimals),
+                )
+            }
+        }
+    }
 }
 
 /// Creates a static `MoveY` event with the timestamp and the Y position of the element
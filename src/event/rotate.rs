@@ -1,20 +1,130 @@
 use crate::easing::Easing;
-use crate::utils::Number;
+use crate::event::stretch_time;
+use crate::utils::{Number, DEFAULT_PRECISION};
 use crate::Event;
+use alloc::{format, string::String};
+use core::f32::consts::PI;
+
+const TAU: f32 = 2. * PI;
 
 /// `Rotate` event
-#[derive(Clone)]
+///
+/// osu! reads rotations in radians, which is what every conversion into a `Rotate` event expects.
+/// Rotations in degrees can be given through [`Degrees`] instead.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Rotate {
     Static(usize, i32, Number),
     Dynamic(usize, Easing, i32, i32, Number, Number),
 }
 
+/// A rotation in degrees, converted to radians when turned into a [`Number`]
+///
+/// `Degrees` can be given anywhere a rotation in radians is expected by a conversion into a
+/// [`Rotate`] event.
+///
+/// Example:
+/// ```
+/// use osb::{event::Degrees, Sprite};
+///
+/// let mut sprite = Sprite::new("res/sprite.png");
+/// sprite.rotate_((0, 1000, Degrees(0.), Degrees(180.)));
+/// assert!(sprite.to_str().contains(" R,0,0,1000,0,3.142\n"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Degrees(pub f32);
+
+impl Into<Number> for Degrees {
+    fn into(self) -> Number {
+        Number::Float(self.0.to_radians()).normalized()
+    }
+}
+
+impl Rotate {
+    /// Returns the rotation set by the `Rotate` event at the timestamp `time`
+    ///
+    /// Before the event starts, its starting rotation is returned, and its ending rotation after it
+    /// ends.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{event::Rotate, utils::Number};
+    ///
+    /// let event: Rotate = (0, 1000, 0, 3).into();
+    /// assert_eq!(event.value_at(500), Number::Float(1.5));
+    /// ```
+    pub fn value_at(&self, time: i32) -> Number {
+        match self {
+            Rotate::Static(_, _, value) => *value,
+            Rotate::Dynamic(_, easing, start_time, end_time, start_value, end_value) => {
+                start_value.lerp(*end_value, easing.progress(time, *start_time, *end_time))
+            }
+        }
+    }
+
+    /// Returns the `Rotate` event with its angles brought back into `[0, 2π)`, which keeps the
+    /// numbers short when rotations are accumulated over time
+    ///
+    /// A static event is normalized without changing the rotation displayed. A dynamic event can't
+    /// always be normalized without changing the direction or the amount it spins, so both of its
+    /// angles are shifted by the same multiple of `2π`, the one bringing its starting angle into
+    /// `[0, 2π)`. Its ending angle may therefore still lie outside of the range.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::event::Rotate;
+    ///
+    /// let event: Rotate = (0, 1000, 7, 8).into();
+    /// let start = event.normalize().value_at(0).as_f32();
+    /// assert!((start - (7. - 2. * std::f32::consts::PI)).abs() < 1e-5);
+    /// ```
+    pub fn normalize(self) -> Rotate {
+        // The multiple of `2π` to remove from `value` to bring it into `[0, 2π)`
+        let offset = |value: Number| {
+            let value = value.as_f32();
+            let mut normalized = value % TAU;
+            if normalized < 0. {
+                normalized += TAU;
+            }
+            value - normalized
+        };
+        let shift = |value: Number, offset: f32| {
+            if offset == 0. {
+                value
+            } else {
+                Number::Float(value.as_f32() - offset).normalized()
+            }
+        };
+        match self {
+            Rotate::Static(depth, time, value) => {
+                Rotate::Static(depth, time, shift(value, offset(value)))
+            }
+            Rotate::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                let offset = offset(start_value);
+                Rotate::Dynamic(
+                    depth,
+                    easing,
+                    start_time,
+                    end_time,
+                    shift(start_value, offset),
+                    shift(end_value, offset),
+                )
+            }
+        }
+    }
+}
+
 impl Event for Rotate {
     fn to_line(&self) -> String {
+        self.to_line_with_precision(DEFAULT_PRECISION)
+    }
+
+    fn to_line_with_precision(&self, decimals: usize) -> String {
         match self {
             Rotate::Static(depth, time, value) => {
                 format!(
-                    "{} R,{},{},,{}",
+                    "{} R,{},{},,{:.decimals$}",
                     " ".repeat(*depth),
                     Easing::Linear.id(),
                     time,
@@ -23,7 +133,7 @@ impl Event for Rotate {
             }
             Rotate::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
                 format!(
-                    "{} R,{},{},{},{},{}",
+                    "{} R,{},{},{},{:.decimals$},{:.decimals$}",
                     " ".repeat(*depth),
                     easing.id(),
                     start_time,
@@ -35,6 +145,10 @@ impl Event for Rotate {
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "R"
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
             Rotate::Static(ref mut current_depth, ..) => *current_depth = depth,
@@ -55,9 +169,110 @@ impl Event for Rotate {
             Rotate::Dynamic(_, _, _, end_time, ..) => *end_time,
         }
     }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            Rotate::Static(depth, time, value) => {
+                Rotate::Static(*depth, total_duration - time, *value)
+            }
+            Rotate::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Rotate::Dynamic(
+                    *depth,
+                    easing.reversed(),
+                    total_duration - end_time,
+                    total_duration - start_time,
+                    *end_value,
+                    *start_value,
+                )
+            }
+        }
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            Rotate::Static(depth, time, value) => Rotate::Static(*depth, time + offset, *value),
+            Rotate::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Rotate::Dynamic(
+                    *depth,
+                    *easing,
+                    start_time + offset,
+                    end_time + offset,
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            Rotate::Static(depth, time, value) => {
+                Rotate::Static(*depth, stretch_time(*time, factor, pivot), *value)
+            }
+            Rotate::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Rotate::Dynamic(
+                    *depth,
+                    *easing,
+                    stretch_time(*start_time, factor, pivot),
+                    stretch_time(*end_time, factor, pivot),
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            Rotate::Static(_, time, _) if *time < start || *time > end => None,
+            Rotate::Dynamic(_, _, start_time, end_time, ..)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            Rotate::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                let (clipped_start, clipped_end) = ((*start_time).max(start), (*end_time).min(end));
+                Some(Rotate::Dynamic(
+                    *depth,
+                    *easing,
+                    clipped_start,
+                    clipped_end,
+                    if clipped_start == *start_time {
+                        *start_value
+                    } else {
+                        self.value_at(clipped_start)
+                    },
+                    if clipped_end == *end_time {
+                        *end_value
+                    } else {
+                        self.value_at(clipped_end)
+                    },
+                ))
+            }
+            event => Some(event.clone()),
+        }
+    }
+
+    fn rounded(&self, decimals: usize) -> Self {
+        match self {
+            Rotate::Static(depth, time, value) => {
+                Rotate::Static(*depth, *time, value.rounded(decimals))
+            }
+            Rotate::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Rotate::Dynamic(
+                    *depth,
+                    *easing,
+                    *start_time,
+                    *end_time,
+                    start_value.rounded(decimals),
+                    end_value.rounded(decimals),
+                )
+            }
+        }
+    }
 }
 
-/// Creates a static `Rotate` event with the timestamp and the rotation of the element
+/// Creates a static `Rotate` event with the timestamp and the rotation of the element, in radians
 ///
 /// Uses a `Linear` easing
 ///
@@ -80,7 +295,8 @@ where
     }
 }
 
-/// Creates a dynamic `Rotate` event with the timestamps and the rotations of the element
+/// Creates a dynamic `Rotate` event with the timestamps and the rotations of the element, in
+/// radians
 ///
 /// Uses a `Linear` easing
 ///
@@ -113,7 +329,8 @@ where
     }
 }
 
-/// Creates a dynamic `Rotate` event with the easing, the timestamps and the rotations of the element
+/// Creates a dynamic `Rotate` event with the easing, the timestamps and the rotations of the
+/// element, in radians
 ///
 /// Example:
 /// ```
@@ -140,7 +357,8 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{event::*, Easing};
+    use crate::{event::*, utils::Number, Easing};
+    use core::f32::consts::PI;
 
     #[test]
     fn to_line_static() {
@@ -161,6 +379,15 @@ mod tests {
         assert_eq!(rotate_event_easing.to_line(), " R,4,0,1000,0,1");
     }
 
+    #[test]
+    fn degrees() {
+        let rotate_event: Rotate = (0, Degrees(90.)).into();
+        assert_eq!(rotate_event.value_at(0), Number::Float(PI / 2.));
+
+        let rotate_event: Rotate = (0, 1000, Degrees(0.), Degrees(-360.)).into();
+        assert_eq!(rotate_event.to_line(), " R,0,0,1000,0,-6.283");
+    }
+
     #[test]
     fn to_line_dynamic_float() {
         let rotate_event: Rotate = (0, 1000, 0.25, 0.75).into();
@@ -169,4 +396,23 @@ mod tests {
         let rotate_event_easing: Rotate = (Easing::QuadOut, 0, 1000, 0.25, 0.75).into();
         assert_eq!(rotate_event_easing.to_line(), " R,4,0,1000,0.25,0.75");
     }
+
+    #[test]
+    fn normalize() {
+        let rotate_event: Rotate = (0, 3. * PI).into();
+        let value = rotate_event.normalize().value_at(0).as_f32();
+        assert!((value - PI).abs() < 1e-5);
+
+        let rotate_event: Rotate = (0, -PI / 2.).into();
+        let value = rotate_event.normalize().value_at(0).as_f32();
+        assert!((value - 3. * PI / 2.).abs() < 1e-5);
+
+        let rotate_event: Rotate = (0, 1000, 1, 20).into();
+        assert_eq!(rotate_event.normalize().to_line(), " R,0,0,1000,1,20");
+
+        let rotate_event: Rotate = (0, 1000, 7. * PI, 6. * PI).into();
+        let normalized = rotate_event.normalize();
+        assert!((normalized.value_at(0).as_f32() - PI).abs() < 1e-5);
+        assert!(normalized.value_at(1000).as_f32().abs() < 1e-5);
+    }
 }
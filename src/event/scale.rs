@@ -1,20 +1,50 @@
 use crate::easing::Easing;
-use crate::utils::Number;
+use crate::event::stretch_time;
+use crate::utils::{Number, DEFAULT_PRECISION};
 use crate::Event;
+use alloc::{format, string::String};
 
 /// `Scale` event
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Scale {
     Static(usize, i32, Number),
     Dynamic(usize, Easing, i32, i32, Number, Number),
 }
 
+impl Scale {
+    /// Returns the scale set by the `Scale` event at the timestamp `time`
+    ///
+    /// Before the event starts, its starting scale is returned, and its ending scale after it ends.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{event::Scale, utils::Number};
+    ///
+    /// let scale: Scale = (0, 1000, 1, 2).into();
+    /// assert_eq!(scale.value_at(500), Number::Float(1.5));
+    /// assert_eq!(scale.value_at(2000), Number::Int(2));
+    /// ```
+    pub fn value_at(&self, time: i32) -> Number {
+        match self {
+            Scale::Static(_, _, value) => *value,
+            Scale::Dynamic(_, easing, start_time, end_time, start_value, end_value) => {
+                start_value.lerp(*end_value, easing.progress(time, *start_time, *end_time))
+            }
+        }
+    }
+}
+
 impl Event for Scale {
     fn to_line(&self) -> String {
+        self.to_line_with_precision(DEFAULT_PRECISION)
+    }
+
+    fn to_line_with_precision(&self, decimals: usize) -> String {
         match self {
             Scale::Static(depth, time, value) => {
                 format!(
-                    "{} S,{},{},,{}",
+                    "{} S,{},{},,{:.decimals$}",
                     " ".repeat(*depth),
                     Easing::Linear.id(),
                     time,
@@ -23,7 +53,7 @@ impl Event for Scale {
             }
             Scale::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
                 format!(
-                    "{} S,{},{},{},{},{}",
+                    "{} S,{},{},{},{:.decimals$},{:.decimals$}",
                     " ".repeat(*depth),
                     easing.id(),
                     start_time,
@@ -35,6 +65,10 @@ impl Event for Scale {
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "S"
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
             Scale::Static(ref mut current_depth, ..) => *current_depth = depth,
@@ -55,6 +89,107 @@ impl Event for Scale {
             Scale::Dynamic(_, _, _, end_time, ..) => *end_time,
         }
     }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            Scale::Static(depth, time, value) => {
+                Scale::Static(*depth, total_duration - time, *value)
+            }
+            Scale::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Scale::Dynamic(
+                    *depth,
+                    easing.reversed(),
+                    total_duration - end_time,
+                    total_duration - start_time,
+                    *end_value,
+                    *start_value,
+                )
+            }
+        }
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            Scale::Static(depth, time, value) => Scale::Static(*depth, time + offset, *value),
+            Scale::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Scale::Dynamic(
+                    *depth,
+                    *easing,
+                    start_time + offset,
+                    end_time + offset,
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            Scale::Static(depth, time, value) => {
+                Scale::Static(*depth, stretch_time(*time, factor, pivot), *value)
+            }
+            Scale::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Scale::Dynamic(
+                    *depth,
+                    *easing,
+                    stretch_time(*start_time, factor, pivot),
+                    stretch_time(*end_time, factor, pivot),
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            Scale::Static(_, time, _) if *time < start || *time > end => None,
+            Scale::Dynamic(_, _, start_time, end_time, ..)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            Scale::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                let (clipped_start, clipped_end) = ((*start_time).max(start), (*end_time).min(end));
+                Some(Scale::Dynamic(
+                    *depth,
+                    *easing,
+                    clipped_start,
+                    clipped_end,
+                    if clipped_start == *start_time {
+                        *start_value
+                    } else {
+                        self.value_at(clipped_start)
+                    },
+                    if clipped_end == *end_time {
+                        *end_value
+                    } else {
+                        self.value_at(clipped_end)
+                    },
+                ))
+            }
+            event => Some(event.clone()),
+        }
+    }
+
+    fn rounded(&self, decimals: usize) -> Self {
+        match self {
+            Scale::Static(depth, time, value) => {
+                Scale::Static(*depth, *time, value.rounded(decimals))
+            }
+            Scale::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                Scale::Dynamic(
+                    *depth,
+                    *easing,
+                    *start_time,
+                    *end_time,
+                    start_value.rounded(decimals),
+                    end_value.rounded(decimals),
+                )
+            }
+        }
+    }
 }
 
 /// Creates a static `Scale` event with the timestamp and the scaling of the element
@@ -1,20 +1,51 @@
 use crate::easing::Easing;
-use crate::utils::{Number, Vec2};
-use crate::Event;
+use crate::event::stretch_time;
+use crate::event::Scale;
+use crate::utils::{Number, Vec2, DEFAULT_PRECISION};
+use crate::{Event, ScaleMismatch};
+use alloc::{format, string::String};
+use core::convert::TryFrom;
 
 /// `ScaleVec` event
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScaleVec {
     Static(usize, i32, Vec2),
     Dynamic(usize, Easing, i32, i32, Vec2, Vec2),
 }
 
+impl ScaleVec {
+    /// Returns the scale set by the `ScaleVec` event at the timestamp `time`
+    ///
+    /// Before the event starts, its starting scale is returned, and its ending scale after it ends.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{event::ScaleVec, utils::Vec2};
+    ///
+    /// let scalevec: ScaleVec = (0, 1000, 1, 1, 2, 3).into();
+    /// assert_eq!(scalevec.value_at(500), Vec2::from(1.5, 2));
+    /// ```
+    pub fn value_at(&self, time: i32) -> Vec2 {
+        match self {
+            ScaleVec::Static(_, _, value) => *value,
+            ScaleVec::Dynamic(_, easing, start_time, end_time, start_value, end_value) => {
+                start_value.lerp(*end_value, easing.progress(time, *start_time, *end_time))
+            }
+        }
+    }
+}
+
 impl Event for ScaleVec {
     fn to_line(&self) -> String {
+        self.to_line_with_precision(DEFAULT_PRECISION)
+    }
+
+    fn to_line_with_precision(&self, decimals: usize) -> String {
         match self {
             ScaleVec::Static(depth, time, scale) => {
                 format!(
-                    "{} V,{},{},,{},{}",
+                    "{} V,{},{},,{:.decimals$},{:.decimals$}",
                     " ".repeat(*depth),
                     Easing::Linear.id(),
                     time,
@@ -24,7 +55,7 @@ impl Event for ScaleVec {
             }
             ScaleVec::Dynamic(depth, easing, start_time, end_time, start_scale, end_scale) => {
                 format!(
-                    "{} V,{},{},{},{},{},{},{}",
+                    "{} V,{},{},{},{:.decimals$},{:.decimals$},{:.decimals$},{:.decimals$}",
                     " ".repeat(*depth),
                     easing.id(),
                     start_time,
@@ -38,6 +69,10 @@ impl Event for ScaleVec {
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "V"
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
             ScaleVec::Static(ref mut current_depth, ..) => *current_depth = depth,
@@ -58,6 +93,107 @@ impl Event for ScaleVec {
             ScaleVec::Dynamic(_, _, _, end_time, ..) => *end_time,
         }
     }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            ScaleVec::Static(depth, time, value) => {
+                ScaleVec::Static(*depth, total_duration - time, *value)
+            }
+            ScaleVec::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                ScaleVec::Dynamic(
+                    *depth,
+                    easing.reversed(),
+                    total_duration - end_time,
+                    total_duration - start_time,
+                    *end_value,
+                    *start_value,
+                )
+            }
+        }
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            ScaleVec::Static(depth, time, value) => ScaleVec::Static(*depth, time + offset, *value),
+            ScaleVec::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                ScaleVec::Dynamic(
+                    *depth,
+                    *easing,
+                    start_time + offset,
+                    end_time + offset,
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            ScaleVec::Static(depth, time, value) => {
+                ScaleVec::Static(*depth, stretch_time(*time, factor, pivot), *value)
+            }
+            ScaleVec::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                ScaleVec::Dynamic(
+                    *depth,
+                    *easing,
+                    stretch_time(*start_time, factor, pivot),
+                    stretch_time(*end_time, factor, pivot),
+                    *start_value,
+                    *end_value,
+                )
+            }
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            ScaleVec::Static(_, time, _) if *time < start || *time > end => None,
+            ScaleVec::Dynamic(_, _, start_time, end_time, ..)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            ScaleVec::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                let (clipped_start, clipped_end) = ((*start_time).max(start), (*end_time).min(end));
+                Some(ScaleVec::Dynamic(
+                    *depth,
+                    *easing,
+                    clipped_start,
+                    clipped_end,
+                    if clipped_start == *start_time {
+                        *start_value
+                    } else {
+                        self.value_at(clipped_start)
+                    },
+                    if clipped_end == *end_time {
+                        *end_value
+                    } else {
+                        self.value_at(clipped_end)
+                    },
+                ))
+            }
+            event => Some(event.clone()),
+        }
+    }
+
+    fn rounded(&self, decimals: usize) -> Self {
+        match self {
+            ScaleVec::Static(depth, time, value) => {
+                ScaleVec::Static(*depth, *time, value.rounded(decimals))
+            }
+            ScaleVec::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                ScaleVec::Dynamic(
+                    *depth,
+                    *easing,
+                    *start_time,
+                    *end_time,
+                    start_value.rounded(decimals),
+                    end_value.rounded(decimals),
+                )
+            }
+        }
+    }
 }
 
 /// Creates a static `ScaleVec` event with the timestamp and the scaling of the element
@@ -221,9 +357,78 @@ where
     }
 }
 
+/// Converts a uniform `Scale` event into a `ScaleVec` event scaling both axes the same way
+///
+/// Example:
+/// ```
+/// use osb::event::{Event, Scale, ScaleVec};
+///
+/// let scale: Scale = (0, 1000, 1, 2).into();
+/// assert_eq!(ScaleVec::from(scale).to_line(), " V,0,0,1000,1,1,2,2");
+/// ```
+impl From<Scale> for ScaleVec {
+    fn from(scale: Scale) -> ScaleVec {
+        match scale {
+            Scale::Static(depth, time, value) => {
+                ScaleVec::Static(depth, time, Vec2::from(value, value))
+            }
+            Scale::Dynamic(depth, easing, start_time, end_time, start_value, end_value) => {
+                ScaleVec::Dynamic(
+                    depth,
+                    easing,
+                    start_time,
+                    end_time,
+                    Vec2::from(start_value, start_value),
+                    Vec2::from(end_value, end_value),
+                )
+            }
+        }
+    }
+}
+
+/// Converts a `ScaleVec` event into a uniform `Scale` event
+///
+/// Fails with a [`ScaleMismatch`] unless both axes hold the same values.
+///
+/// Example:
+/// ```
+/// use osb::event::{Event, Scale, ScaleVec};
+/// use std::convert::TryFrom;
+///
+/// let scalevec: ScaleVec = (0, 1000, 1, 1, 2, 2).into();
+/// let scale = Scale::try_from(scalevec).unwrap();
+/// assert_eq!(scale.to_line(), " S,0,0,1000,1,2");
+/// ```
+impl TryFrom<ScaleVec> for Scale {
+    type Error = ScaleMismatch;
+
+    fn try_from(scalevec: ScaleVec) -> Result<Scale, ScaleMismatch> {
+        let is_uniform = |value: Vec2| value.x.normalized() == value.y.normalized();
+        match scalevec {
+            ScaleVec::Static(depth, time, value) if is_uniform(value) => {
+                Ok(Scale::Static(depth, time, value.x))
+            }
+            ScaleVec::Dynamic(depth, easing, start_time, end_time, start_value, end_value)
+                if is_uniform(start_value) && is_uniform(end_value) =>
+            {
+                Ok(Scale::Dynamic(
+                    depth,
+                    easing,
+                    start_time,
+                    end_time,
+                    start_value.x,
+                    end_value.x,
+                ))
+            }
+            _ => Err(ScaleMismatch),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{event::*, utils::Vec2, Easing};
+    use crate::{event::*, utils::Vec2, Easing, ScaleMismatch};
+    use core::convert::TryFrom;
 
     #[test]
     fn to_line_static() {
@@ -246,4 +451,26 @@ mod tests {
         let scalevec_event_easing: ScaleVec = (Easing::QuadOut, 0, 1000, 1, 0, 1, 1).into();
         assert_eq!(scalevec_event_easing.to_line(), " V,4,0,1000,1,0,1,1");
     }
+
+    #[test]
+    fn from_scale() {
+        let scale: Scale = (100, 0.5).into();
+        assert_eq!(ScaleVec::from(scale).to_line(), " V,0,100,,0.5,0.5");
+
+        let scale: Scale = (Easing::QuadOut, 0, 1000, 0, 1).into();
+        assert_eq!(ScaleVec::from(scale).to_line(), " V,4,0,1000,0,0,1,1");
+    }
+
+    #[test]
+    fn try_into_scale() {
+        let scalevec: ScaleVec = (100, 2, 2.0).into();
+        let scale = Scale::try_from(scalevec);
+        assert_eq!(
+            scale.ok().map(|scale| scale.to_line()).as_deref(),
+            Some(" S,0,100,,2")
+        );
+
+        let scalevec: ScaleVec = (0, 1000, 1, 1, 1, 0).into();
+        assert_eq!(Scale::try_from(scalevec).err(), Some(ScaleMismatch));
+    }
 }
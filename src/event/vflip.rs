@@ -1,15 +1,27 @@
 use crate::easing::Easing;
+use crate::event::stretch_time;
 use crate::Event;
+use alloc::{format, string::String};
 
 /// `VFlip` event
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VFlip {
+    Static(usize, i32),
     Dynamic(usize, Easing, i32, i32),
 }
 
 impl Event for VFlip {
     fn to_line(&self) -> String {
         match self {
+            VFlip::Static(depth, time) => {
+                format!(
+                    "{} P,{},{},,V",
+                    " ".repeat(*depth),
+                    Easing::Linear.id(),
+                    time
+                )
+            }
             VFlip::Dynamic(depth, easing, start_time, end_time) => {
                 format!(
                     "{} P,{},{},{},V",
@@ -22,23 +34,109 @@ impl Event for VFlip {
         }
     }
 
+    fn command_char(&self) -> &'static str {
+        "P"
+    }
+
+    fn parameter_suffix(&self) -> Option<char> {
+        Some('V')
+    }
+
     fn set_depth(&mut self, depth: usize) {
         match self {
-            VFlip::Dynamic(ref mut current_depth, ..) => *current_depth = depth,
+            VFlip::Static(ref mut current_depth, ..)
+            | VFlip::Dynamic(ref mut current_depth, ..) => *current_depth = depth,
         }
     }
 
     fn get_start_time(&self) -> i32 {
         match self {
+            VFlip::Static(_, time) => *time,
             VFlip::Dynamic(_, _, start_time, _) => *start_time,
         }
     }
 
     fn get_end_time(&self) -> i32 {
         match self {
+            VFlip::Static(_, time) => *time,
             VFlip::Dynamic(_, _, _, end_time) => *end_time,
         }
     }
+
+    fn reversed(&self, total_duration: i32) -> Self {
+        match self {
+            VFlip::Static(depth, time) => VFlip::Static(*depth, total_duration - time),
+            VFlip::Dynamic(depth, easing, start_time, end_time) => VFlip::Dynamic(
+                *depth,
+                easing.reversed(),
+                total_duration - end_time,
+                total_duration - start_time,
+            ),
+        }
+    }
+
+    fn shifted(&self, offset: i32) -> Self {
+        match self {
+            VFlip::Static(depth, time) => VFlip::Static(*depth, time + offset),
+            VFlip::Dynamic(depth, easing, start_time, end_time) => {
+                VFlip::Dynamic(*depth, *easing, start_time + offset, end_time + offset)
+            }
+        }
+    }
+
+    fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        match self {
+            VFlip::Static(depth, time) => VFlip::Static(*depth, stretch_time(*time, factor, pivot)),
+            VFlip::Dynamic(depth, easing, start_time, end_time) => VFlip::Dynamic(
+                *depth,
+                *easing,
+                stretch_time(*start_time, factor, pivot),
+                stretch_time(*end_time, factor, pivot),
+            ),
+        }
+    }
+
+    fn clip(&self, start: i32, end: i32) -> Option<Self> {
+        match self {
+            // A static flip stays on from its time onward, one set before `start` still applies
+            VFlip::Static(_, time) if *time > end => None,
+            VFlip::Static(depth, time) => Some(VFlip::Static(*depth, (*time).max(start))),
+            VFlip::Dynamic(_, _, start_time, end_time)
+                if *end_time < start || *start_time > end =>
+            {
+                None
+            }
+            VFlip::Dynamic(depth, easing, start_time, end_time) => Some(VFlip::Dynamic(
+                *depth,
+                *easing,
+                (*start_time).max(start),
+                (*end_time).min(end),
+            )),
+        }
+    }
+
+    fn rounded(&self, _decimals: usize) -> Self {
+        // Parameter events hold no values, there is nothing to round
+        self.clone()
+    }
+}
+
+/// Creates a static `VFlip` event with the timestamp
+///
+/// Example:
+/// ```
+/// use osb::Sprite;
+///
+/// let time = 5000;
+///
+/// let mut sprite = Sprite::new("res/sprite.png");
+/// sprite.vflip_(time);
+/// assert!(sprite.to_str().contains(" P,0,5000,,V\n"));
+/// ```
+impl Into<VFlip> for i32 {
+    fn into(self) -> VFlip {
+        VFlip::Static(0, self)
+    }
 }
 
 /// Creates a `VFlip` event with the timestamps
@@ -93,4 +191,26 @@ mod tests {
         vflip_event_depth.set_depth(2);
         assert_eq!(vflip_event_depth.to_line(), "   P,4,0,1000,V");
     }
+
+    #[test]
+    fn to_line_static() {
+        let mut vflip_event: VFlip = 5000.into();
+        assert_eq!(vflip_event.to_line(), " P,0,5000,,V");
+        assert_eq!(vflip_event.get_start_time(), 5000);
+        assert_eq!(vflip_event.get_end_time(), 5000);
+
+        vflip_event.set_depth(1);
+        assert_eq!(vflip_event.to_line(), "  P,0,5000,,V");
+        assert_eq!(vflip_event.reversed(6000).to_line(), "  P,0,1000,,V");
+        assert_eq!(vflip_event.shifted(-1000).to_line(), "  P,0,4000,,V");
+        assert!(vflip_event.clip(0, 4999).is_none());
+        assert_eq!(
+            vflip_event.clip(6000, 7000).unwrap().to_line(),
+            "  P,0,6000,,V"
+        );
+        assert_eq!(
+            vflip_event.clip(5000, 7000).unwrap().to_line(),
+            "  P,0,5000,,V"
+        );
+    }
 }
@@ -1,7 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 /// `Layer`s as defined in the [official osu! specifications](https://osu.ppy.sh/wiki/en/Storyboard_Scripting/General_Rules#layers)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Layer {
     /// Default and preferred layer
     Background,
@@ -22,6 +23,27 @@ pub enum Layer {
     Overlay,
 }
 
+impl Layer {
+    /// Returns the index osu! gives to a `Layer`, going from `0` for `Background` to `4` for
+    /// `Overlay`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Layer;
+    /// assert_eq!(Layer::Background.index(), 0);
+    /// assert_eq!(Layer::Foreground.index(), 3);
+    /// ```
+    pub fn index(self) -> u8 {
+        match self {
+            Layer::Background => 0,
+            Layer::Fail => 1,
+            Layer::Pass => 2,
+            Layer::Foreground => 3,
+            Layer::Overlay => 4,
+        }
+    }
+}
+
 impl fmt::Display for Layer {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -50,4 +72,12 @@ mod tests {
         assert_eq!(format!("{}", Layer::Foreground), "Foreground");
         assert_eq!(format!("{}", Layer::Overlay), "Overlay");
     }
+
+    #[test]
+    fn hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Layer::Background);
+        assert!(!set.insert(Layer::Background));
+        assert!(set.insert(Layer::Overlay));
+    }
 }
@@ -1,5 +1,6 @@
 #![crate_type = "lib"]
 #![crate_name = "osb"]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 /*!
  * An easy-to-use library to create, read, parse and modify .osb storyboard files.
@@ -21,10 +22,10 @@
  *     module
  * }
  *
- * fn main() -> std::io::Result<()> {
+ * fn main() {
  *     let mut sb = Storyboard::new();
  *     sb.push(module());
- *     sb.print()
+ *     print!("{}", sb.render());
  * }
  * ```
  *
@@ -37,8 +38,19 @@
  * If you are new to storyboarding, we'd recommend you to develop knowledge on this field first.
  * If you have no idea where to start, a great source of knowledge is PoNo's
  * [osbx wiki](https://wiki.osbx.org/storyboard/osb).
+ *
+ * # Features
+ *
+ * - `std` *(enabled by default)*: printing a `Storyboard` to `stdout` and every other helper
+ *   relying on `std::io`. Without it, the crate is `no_std` and only needs `alloc`, storyboards
+ *   can still be rendered to a `String` through their `Display` implementation.
+ * - `serde`: `Serialize` and `Deserialize` implementations for [`Storyboard`]s, [`Module`]s,
+ *   [`Sprite`]s, their events and every type they are made of, to save a project in any format
+ *   supported by `serde` and reload it later on.
  */
 
+extern crate alloc;
+
 /// All of the storyboard events, `Move`, `Scale`, ... and the trait `Event` defining them
 pub mod event;
 pub use event::Event;
@@ -46,6 +58,8 @@ pub use event::Event;
 /// The utils, everything we need in order to make `osb` work
 pub mod utils;
 
+mod math;
+
 mod easing;
 pub use easing::*;
 
@@ -63,3 +77,14 @@ pub use visuals::*;
 
 mod module;
 pub use module::*;
+
+mod error;
+pub use error::*;
+
+mod lint;
+pub use lint::*;
+
+mod sample;
+pub use sample::*;
+
+mod parse;
@@ -0,0 +1,78 @@
+use crate::utils::Vec2;
+use core::fmt;
+
+// Horizontal bounds of the visible playfield, the vertical ones being `0..=480` in both cases
+const PLAYFIELD_X: (f32, f32) = (0., 640.);
+const WIDESCREEN_PLAYFIELD_X: (f32, f32) = (-107., 747.);
+const PLAYFIELD_Y: (f32, f32) = (0., 480.);
+
+/// Mistakes spotted while building a [`Sprite`](crate::Sprite)
+///
+/// `Lint`s are only reported, the events they concern are left untouched.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Lint {
+    /// A [`Move`](crate::event::Move) event places the `Sprite` at `pos` at the timestamp `time`,
+    /// outside of the visible playfield
+    OffScreen { time: i32, pos: Vec2 },
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lint::OffScreen { time, pos } => {
+                write!(f, "off-screen position {},{} at {}", pos.x, pos.y, time)
+            }
+        }
+    }
+}
+
+/// Issues spotted by [`Sprite::validate`](crate::Sprite::validate) on a whole
+/// [`Sprite`](crate::Sprite)
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpriteWarning {
+    /// The `Sprite` has events but no [`Fade`](crate::event::Fade) event, so its opacity is never
+    /// set and it shows for its whole lifetime
+    NoFade,
+    /// Some events of the `Sprite` happen before `start_time` or after `end_time`, the first and
+    /// last timestamps of its [`Fade`](crate::event::Fade) events
+    BeyondFade { start_time: i32, end_time: i32 },
+    /// An event of the `Sprite` ends at `end_time`, before it starts at `start_time`
+    EndBeforeStart { start_time: i32, end_time: i32 },
+}
+
+impl fmt::Display for SpriteWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpriteWarning::NoFade => write!(f, "no fade command"),
+            SpriteWarning::BeyondFade {
+                start_time,
+                end_time,
+            } => write!(
+                f,
+                "events beyond the fade window from {} to {}",
+                start_time, end_time
+            ),
+            SpriteWarning::EndBeforeStart {
+                start_time,
+                end_time,
+            } => write!(
+                f,
+                "event ending at {} before starting at {}",
+                end_time, start_time
+            ),
+        }
+    }
+}
+
+// Whether `pos` lies inside of the visible playfield
+pub(crate) fn is_on_screen(pos: Vec2, widescreen: bool) -> bool {
+    let (min_x, max_x) = if widescreen {
+        WIDESCREEN_PLAYFIELD_X
+    } else {
+        PLAYFIELD_X
+    };
+    let (x, y) = (pos.x.as_f32(), pos.y.as_f32());
+    min_x <= x && x <= max_x && PLAYFIELD_Y.0 <= y && y <= PLAYFIELD_Y.1
+}
@@ -0,0 +1,81 @@
+// Floating point functions missing from `core`, relying on `libm` when `std` is not available
+
+#[cfg(feature = "std")]
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    x.powf(y)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    libm::powf(x, y)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sqrt(x: f32) -> f32 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sin(x: f32) -> f32 {
+    x.sin()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sin(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn cos(x: f32) -> f32 {
+    x.cos()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn cos(x: f32) -> f32 {
+    libm::cosf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn trunc(x: f32) -> f32 {
+    x.trunc()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn trunc(x: f32) -> f32 {
+    libm::truncf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn round(x: f32) -> f32 {
+    x.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(x: f32) -> f32 {
+    libm::roundf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn floor(x: f32) -> f32 {
+    x.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn floor(x: f32) -> f32 {
+    libm::floorf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn ceil(x: f32) -> f32 {
+    x.ceil()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn ceil(x: f32) -> f32 {
+    libm::ceilf(x)
+}
@@ -1,4 +1,11 @@
-use crate::{Layer, Sprite};
+use crate::utils::{Number, Vec2};
+use crate::{EventKindCounts, Layer, LayerMismatch, Origin, Sprite};
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
 
 /// A component of a `Storyboard`
 ///
@@ -14,9 +21,12 @@ use crate::{Layer, Sprite};
 /// of its own depending on the complexity of your module.
 ///
 /// A `Module` is tied to a [`Layer`] and cannot be changed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Module {
     layer: Layer,
     sprites: Vec<Sprite>,
+    // The depth given to the next `Sprite` pushed without one
+    next_depth: usize,
 }
 
 impl Module {
@@ -25,11 +35,15 @@ impl Module {
         Self {
             layer,
             sprites: vec![],
+            next_depth: 0,
         }
     }
 
     /// Adds a [`Sprite`] to a `Module`
     ///
+    /// A `Sprite` without a [depth](Sprite::set_depth) is given one above every `Sprite` pushed
+    /// before it, so that it draws on top of them. The `Sprite`s are kept sorted by depth.
+    ///
     /// Usage:
     /// ```
     /// use osb::{Layer, Module, Sprite};
@@ -39,20 +53,282 @@ impl Module {
     /// ```
     pub fn push(&mut self, mut sprite: Sprite) {
         sprite.set_layer(self.layer);
-        self.sprites.push(sprite);
+        let depth = sprite.depth().unwrap_or(self.next_depth);
+        sprite.set_depth(depth);
+        self.next_depth = self.next_depth.max(depth + 1);
+        let index = self
+            .sprites
+            .partition_point(|other| other.depth() <= Some(depth));
+        self.sprites.insert(index, sprite);
+    }
+
+    /// Moves every [`Sprite`] of `other` to the `Module`, above the `Sprite`s already in it
+    ///
+    /// The [depths](Sprite::set_depth) of the `Sprite`s of `other` are shifted so that they keep
+    /// their order while drawing above every `Sprite` of the `Module`. Both `Module`s must share
+    /// the same [`Layer`], a [`LayerMismatch`] is returned otherwise and the `Module` is left
+    /// untouched.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite};
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new("res/below.png"));
+    ///
+    /// let mut other = Module::new(Layer::Background);
+    /// other.push(Sprite::new("res/above.png"));
+    /// module.append(other).unwrap();
+    /// assert!(module.output().ends_with("\"res/above.png\",320,240\n"));
+    ///
+    /// assert!(module.append(Module::new(Layer::Overlay)).is_err());
+    /// ```
+    pub fn append(&mut self, other: Module) -> Result<(), LayerMismatch> {
+        if self.layer != other.layer {
+            return Err(LayerMismatch(self.layer, other.layer));
+        }
+
+        for mut sprite in other.sprites {
+            sprite.set_depth(self.next_depth + sprite.depth().unwrap_or(0));
+            self.sprites.push(sprite);
+        }
+        self.next_depth += other.next_depth;
+        Ok(())
+    }
+
+    /// Adds a grid of `cols` by `rows` identical [`Sprite`]s to a `Module`
+    ///
+    /// The `Sprite` on the column `col` and the row `row` is placed at
+    /// `start + (col * spacing.x, row * spacing.y)`, the grid being pushed row by row.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{utils::Vec2, Layer, Module, Origin};
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push_grid(
+    ///     "res/dot.png",
+    ///     Origin::Centre,
+    ///     16,
+    ///     9,
+    ///     Vec2::from(40, 40),
+    ///     Vec2::from(20, 60),
+    /// );
+    /// ```
+    pub fn push_grid(
+        &mut self,
+        path: &str,
+        origin: Origin,
+        cols: u32,
+        rows: u32,
+        spacing: Vec2,
+        start: Vec2,
+    ) {
+        for row in 0..rows {
+            for col in 0..cols {
+                let offset = Vec2::from(
+                    Number::Float(col as f32 * spacing.x.as_f32()).normalized(),
+                    Number::Float(row as f32 * spacing.y.as_f32()).normalized(),
+                );
+                self.push(Sprite::new((origin, path, start + offset)));
+            }
+        }
+    }
+
+    /// Adds an animation made of the explicit list of `frames` to a `Module`
+    ///
+    /// One [`Sprite`] is pushed per frame, the frame `i` being only shown from `i * frame_delay` to
+    /// `(i + 1) * frame_delay`. The frames don't need to follow the `base0..baseN` naming convention
+    /// of osu! animations.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{utils::Vec2, Layer, Module, Origin};
+    /// let mut module = Module::new(Layer::Foreground);
+    /// module.push_frame_sequence(
+    ///     &["res/idle.png", "res/blink.png", "res/idle.png"],
+    ///     100,
+    ///     Origin::Centre,
+    ///     Vec2::from(320, 240),
+    /// );
+    /// ```
+    pub fn push_frame_sequence(
+        &mut self,
+        frames: &[&str],
+        frame_delay: i32,
+        origin: Origin,
+        pos: Vec2,
+    ) {
+        for (i, path) in frames.iter().enumerate() {
+            let start_time = i as i32 * frame_delay;
+            let mut sprite = Sprite::new((origin, *path, pos));
+            sprite.fade_((start_time, start_time + frame_delay, 1, 1));
+            self.push(sprite);
+        }
+    }
+
+    /// Removes the [`Sprite`]s rendering exactly like an earlier one
+    ///
+    /// The first occurrence of each `Sprite` is kept, preserving the order of the `Module`.
+    pub fn dedup_sprites(&mut self) {
+        let mut seen = BTreeSet::new();
+        self.sprites.retain(|sprite| seen.insert(sprite.to_str()));
+    }
+
+    /// Keeps only the [`Sprite`]s whose lifetime overlaps the window going from `start` to `end`
+    ///
+    /// See [`Sprite::overlaps`] for more details.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite};
+    /// let mut module = Module::new(Layer::Background);
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.fade_((100, 900, 0, 1));
+    /// module.push(sprite);
+    ///
+    /// module.retain_overlapping(1000, 2000);
+    /// assert_eq!(module.output(), "");
+    /// ```
+    pub fn retain_overlapping(&mut self, start: i32, end: i32) {
+        self.sprites.retain(|sprite| sprite.overlaps(start, end));
+    }
+
+    /// Returns a copy of the `Module` whose [`Sprite`]s play backwards in a storyboard of length
+    /// `total_duration`
+    ///
+    /// See [`Sprite::reversed`] for more details.
+    pub fn reversed(&self, total_duration: i32) -> Module {
+        Module {
+            layer: self.layer,
+            sprites: self
+                .sprites
+                .iter()
+                .map(|sprite| sprite.reversed(total_duration))
+                .collect(),
+            next_depth: self.next_depth,
+        }
+    }
+
+    /// Returns a copy of the `Module` and of its [`Sprite`]s placed on `layer`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Layer, Module, Sprite};
+    ///
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new("res/sprite.png"));
+    ///
+    /// let foreground = module.with_layer(Layer::Foreground);
+    /// assert_eq!(foreground.layer(), Layer::Foreground);
+    /// assert_eq!(
+    ///     foreground.output(),
+    ///     "Sprite,Foreground,Centre,\"res/sprite.png\",320,240\n"
+    /// );
+    /// assert_eq!(module.layer(), Layer::Background);
+    /// ```
+    pub fn with_layer(&self, layer: Layer) -> Module {
+        Module {
+            layer,
+            sprites: self
+                .sprites
+                .iter()
+                .map(|sprite| {
+                    let mut sprite = sprite.clone();
+                    sprite.set_layer(layer);
+                    sprite
+                })
+                .collect(),
+            next_depth: self.next_depth,
+        }
     }
 
     /// Returns the contents of the `Module`
     ///
     /// **Warning**: this method is not meant to be used
     pub fn output(&self) -> String {
+        self.render(false)
+    }
+
+    // The contents of the `Module`, see `Sprite::render`
+    pub(crate) fn render(&self, explicit_loop_type: bool) -> String {
         self.sprites
             .iter()
-            .map(|spr| spr.to_str())
+            .map(|spr| spr.render(explicit_loop_type))
             .collect::<Vec<String>>()
             .join("")
     }
 
+    /// Writes the contents of the `Module` to `w` line by line, without rendering them as a whole
+    ///
+    /// See [`Sprite::write_to`] for more details.
+    ///
+    /// Requires the `std` feature
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.write_rendered(w, false)
+    }
+
+    // Writes what `render` returns to `w`
+    #[cfg(feature = "std")]
+    pub(crate) fn write_rendered<W: Write>(
+        &self,
+        w: &mut W,
+        explicit_loop_type: bool,
+    ) -> io::Result<()> {
+        for sprite in &self.sprites {
+            sprite.write_rendered(w, explicit_loop_type)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of lines the `Module` renders to, without rendering it
+    ///
+    /// See [`Sprite::line_count`] for more details.
+    pub fn line_count(&self) -> usize {
+        self.sprites.iter().map(Sprite::line_count).sum()
+    }
+
+    /// Returns the number of bytes the `Module` renders to, without rendering it as a whole
+    ///
+    /// See [`Sprite::byte_len`] for more details.
+    pub fn byte_len(&self) -> usize {
+        self.rendered_len(false)
+    }
+
+    // Number of bytes `render` writes
+    pub(crate) fn rendered_len(&self, explicit_loop_type: bool) -> usize {
+        self.sprites
+            .iter()
+            .map(|sprite| sprite.rendered_len(explicit_loop_type))
+            .sum()
+    }
+
+    /// Returns the number of events of each kind held by the [`Sprite`]s of the `Module`, which
+    /// shows where optimizing pays off
+    ///
+    /// See [`Sprite::event_kind_counts`] for more details.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite};
+    /// let mut module = Module::new(Layer::Background);
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240));
+    /// module.push(sprite);
+    ///
+    /// assert_eq!(module.event_kind_counts().move_, 1);
+    /// ```
+    pub fn event_kind_counts(&self) -> EventKindCounts {
+        self.sprites
+            .iter()
+            .map(Sprite::event_kind_counts)
+            .fold(EventKindCounts::default(), |total, counts| total + counts)
+    }
+
+    // The `Sprite`s of the `Module`, sorted by depth
+    pub(crate) fn sprites(&self) -> &[Sprite] {
+        &self.sprites
+    }
+
     /// Returns the layer of the `Module`
     ///
     /// Example:
@@ -64,4 +340,197 @@ impl Module {
     pub fn layer(&self) -> Layer {
         self.layer
     }
+
+    /// Returns the number of [`Sprite`]s in the `Module`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Layer, Module, Sprite};
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new("res/sprite.png"));
+    /// assert_eq!(module.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.sprites.len()
+    }
+
+    /// Returns `true` if the `Module` holds no [`Sprite`]
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Layer, Module, Sprite};
+    /// let mut module = Module::new(Layer::Background);
+    /// assert!(module.is_empty());
+    ///
+    /// module.push(Sprite::new("res/sprite.png"));
+    /// assert!(!module.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.sprites.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils::Vec2, EventKindCounts, Layer, LayerMismatch, Module, Origin, Sprite};
+
+    #[test]
+    fn push_grid() {
+        let mut module = Module::new(Layer::Foreground);
+        module.push_grid(
+            "sb/dot.png",
+            Origin::TopLeft,
+            2,
+            2,
+            Vec2::from(100, 50.5),
+            Vec2::from(10, 20),
+        );
+        assert_eq!(
+            module.output(),
+            "Sprite,Foreground,TopLeft,\"sb/dot.png\",10,20
+Sprite,Foreground,TopLeft,\"sb/dot.png\",110,20
+Sprite,Foreground,TopLeft,\"sb/dot.png\",10,70.5
+Sprite,Foreground,TopLeft,\"sb/dot.png\",110,70.5
+"
+        );
+    }
+
+    #[test]
+    fn push_frame_sequence() {
+        let mut module = Module::new(Layer::Foreground);
+        module.push_frame_sequence(
+            &["sb/a.png", "sb/b.png", "sb/c.png"],
+            100,
+            Origin::Centre,
+            Vec2::from(320, 240),
+        );
+        assert_eq!(module.len(), 3);
+        assert_eq!(
+            module.output(),
+            "Sprite,Foreground,Centre,\"sb/a.png\",320,240
+ F,0,0,100,1,1
+Sprite,Foreground,Centre,\"sb/b.png\",320,240
+ F,0,100,200,1,1
+Sprite,Foreground,Centre,\"sb/c.png\",320,240
+ F,0,200,300,1,1
+"
+        );
+    }
+
+    #[test]
+    fn event_kind_counts() {
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new("sb/sprite.png");
+        sprite
+            .move_((0, 1000, 0, 0, 320, 240))
+            .fade_((0, 1000, 0, 1));
+        module.push(sprite);
+        let mut sprite = Sprite::new("sb/sprite.png");
+        sprite.move_((500, 2000, 320, 240, 0, 0));
+        module.push(sprite);
+
+        assert_eq!(
+            module.event_kind_counts(),
+            EventKindCounts {
+                move_: 2,
+                fade: 1,
+                ..EventKindCounts::default()
+            }
+        );
+        assert_eq!(module.event_kind_counts().total(), 3);
+    }
+
+    #[test]
+    fn append() {
+        let mut module = Module::new(Layer::Foreground);
+        module.push(Sprite::new("a"));
+        let mut sprite = Sprite::new("b");
+        sprite.set_depth(3);
+        module.push(sprite);
+
+        let mut other = Module::new(Layer::Foreground);
+        other.push(Sprite::new("c"));
+        other.push(Sprite::new("d"));
+        module.append(other).unwrap();
+        module.push(Sprite::new("e"));
+
+        let depths = module
+            .sprites()
+            .iter()
+            .map(Sprite::depth)
+            .collect::<Vec<Option<usize>>>();
+        assert_eq!(depths, [Some(0), Some(3), Some(4), Some(5), Some(6)]);
+
+        assert_eq!(
+            module.append(Module::new(Layer::Pass)),
+            Err(LayerMismatch(Layer::Foreground, Layer::Pass))
+        );
+        assert_eq!(module.len(), 5);
+    }
+
+    #[test]
+    fn with_layer() {
+        let mut module = Module::new(Layer::Background);
+        let mut sprite = Sprite::new("a");
+        sprite.fade_((0, 1000, 0, 1));
+        module.push(sprite);
+        module.push(Sprite::new("b"));
+
+        let mut other = module.with_layer(Layer::Overlay);
+        other.push(Sprite::new("c"));
+        assert_eq!(
+            other.output(),
+            "Sprite,Overlay,Centre,\"a\",320,240
+ F,0,0,1000,0,1
+Sprite,Overlay,Centre,\"b\",320,240
+Sprite,Overlay,Centre,\"c\",320,240
+"
+        );
+        assert_eq!(other.sprites()[2].depth(), Some(2));
+        assert_eq!(module.len(), 2);
+        assert!(module.output().starts_with("Sprite,Background"));
+    }
+
+    #[test]
+    fn dedup_sprites_keeps_order() {
+        let mut module = Module::new(Layer::Background);
+        for (first, second) in [(true, false), (false, true), (true, false)] {
+            let mut sprite = Sprite::new("res/sprite.png");
+            for (time, fade) in [(0, first), (1000, second)] {
+                let mut block = sprite.loop_(time, 2);
+                if fade {
+                    block.fade_((0, 500, 0, 1));
+                } else {
+                    block.move_((0, 500, 0, 0, 320, 240));
+                }
+            }
+            module.push(sprite);
+        }
+
+        module.dedup_sprites();
+        assert_eq!(module.len(), 2);
+        assert_ne!(module.sprites()[0].to_str(), module.sprites()[1].to_str());
+    }
+
+    #[test]
+    fn depth() {
+        let mut module = Module::new(Layer::Foreground);
+        for (path, depth) in [("a", None), ("b", Some(5)), ("c", Some(0)), ("d", None)] {
+            let mut sprite = Sprite::new(path);
+            if let Some(depth) = depth {
+                sprite.set_depth(depth);
+            }
+            module.push(sprite);
+        }
+
+        let output = module.output();
+        let paths = output.split('"').skip(1).step_by(2).collect::<Vec<&str>>();
+        assert_eq!(paths, ["a", "c", "b", "d"]);
+        let depths = module
+            .sprites()
+            .iter()
+            .map(Sprite::depth)
+            .collect::<Vec<Option<usize>>>();
+        assert_eq!(depths, [Some(0), Some(0), Some(5), Some(6)]);
+    }
 }
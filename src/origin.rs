@@ -1,7 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 /// `Origin`s as defined in the [official osu! specifications](https://osu.ppy.sh/wiki/en/Storyboard_Scripting/Objects)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Origin {
     TopLeft,
     TopCentre,
@@ -14,6 +15,32 @@ pub enum Origin {
     BottomRight,
 }
 
+impl Origin {
+    /// Returns the index of an `Origin`, going from `0` for `TopLeft` to `8` for `BottomRight`
+    /// row by row
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Origin;
+    /// assert_eq!(Origin::TopLeft.index(), 0);
+    /// assert_eq!(Origin::Centre.index(), 4);
+    /// assert_eq!(Origin::BottomRight.index(), 8);
+    /// ```
+    pub fn index(self) -> u8 {
+        match self {
+            Origin::TopLeft => 0,
+            Origin::TopCentre => 1,
+            Origin::TopRight => 2,
+            Origin::CentreLeft => 3,
+            Origin::Centre => 4,
+            Origin::CentreRight => 5,
+            Origin::BottomLeft => 6,
+            Origin::BottomCentre => 7,
+            Origin::BottomRight => 8,
+        }
+    }
+}
+
 impl fmt::Display for Origin {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -50,4 +77,25 @@ mod tests {
         assert_eq!(format!("{}", Origin::BottomCentre), "BottomCentre");
         assert_eq!(format!("{}", Origin::BottomRight), "BottomRight");
     }
+
+    #[test]
+    fn hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Origin::Centre);
+        assert!(!set.insert(Origin::Centre));
+        assert!(set.insert(Origin::TopLeft));
+    }
+
+    #[test]
+    fn index() {
+        assert_eq!(Origin::TopLeft.index(), 0);
+        assert_eq!(Origin::TopCentre.index(), 1);
+        assert_eq!(Origin::TopRight.index(), 2);
+        assert_eq!(Origin::CentreLeft.index(), 3);
+        assert_eq!(Origin::Centre.index(), 4);
+        assert_eq!(Origin::CentreRight.index(), 5);
+        assert_eq!(Origin::BottomLeft.index(), 6);
+        assert_eq!(Origin::BottomCentre.index(), 7);
+        assert_eq!(Origin::BottomRight.index(), 8);
+    }
 }
@@ -0,0 +1,769 @@
+use crate::event::*;
+use crate::utils::{self, Number, Vec2};
+use crate::{
+    math, Easing, Layer, LoopType, Module, Origin, ParseError, ParseErrorKind, Sample, Sprite,
+    Storyboard, TriggerType,
+};
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+// What the nested commands of a sprite are pushed to
+enum Group {
+    None,
+    Loop,
+    Trigger,
+    // A group osu! supports but `osb` can't represent, kept verbatim
+    Raw,
+}
+
+// The object declared by the last unindented line
+enum Object {
+    None,
+    Sprite,
+    // An object `osb` has no representation for, along with its commands
+    Skipped,
+}
+
+// Pushes an `AnyEvent` to a `Sprite` or to one of its groups
+macro_rules! push_event {
+    ($target:expr, $event:expr) => {
+        match $event {
+            AnyEvent::Move(event) => $target.move_(event),
+            AnyEvent::MoveX(event) => $target.movex_(event),
+            AnyEvent::MoveY(event) => $target.movey_(event),
+            AnyEvent::Fade(event) => $target.fade_(event),
+            AnyEvent::Rotate(event) => $target.rotate_(event),
+            AnyEvent::Scale(event) => $target.scale_(event),
+            AnyEvent::ScaleVec(event) => $target.scalevec_(event),
+            AnyEvent::Color(event) => $target.color_(event),
+            AnyEvent::HFlip(event) => $target.hflip_(event),
+            AnyEvent::VFlip(event) => $target.vflip_(event),
+            AnyEvent::Additive(event) => $target.additive_(event),
+        }
+    };
+}
+
+/// Parses the `[Events]` section of a `.osb` file
+///
+/// `Sprite` and `Animation` declarations are placed in a [`Module`] of their [`Layer`], one
+/// `Module` per `Layer`, and their commands are parsed back into events. A command holding more
+/// values than a start and an end one is split into as many events, except for chained `Color`
+/// events. The commands `osb` doesn't know are kept verbatim within their sprite or block, see
+/// [`Sprite::push_raw`]. [`Sample`]s are pushed to the `Storyboard` as well. Comments, other
+/// sections and the objects `osb` has no representation for, such as videos, are skipped.
+///
+/// Example:
+/// ```
+/// use osb::Storyboard;
+///
+/// let osb = "[Events]\nSprite,Foreground,Centre,\"res/sprite.png\",320,240\n M,0,0,1000,0,0,320,240";
+/// let sb: Storyboard = osb.parse().unwrap();
+/// assert!(sb.render().contains(" M,0,0,1000,0,0,320,240"));
+///
+/// let error = "[Events]\n M,0,0,1000,0,0,320,240".parse::<Storyboard>().err().unwrap();
+/// assert_eq!(error.line, 2);
+/// ```
+impl FromStr for Storyboard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Storyboard, ParseError> {
+        let mut sprites: Vec<(Layer, Sprite)> = Vec::new();
+        let mut samples = Vec::new();
+        let (mut object, mut group) = (Object::None, Group::None);
+        let mut in_events = true;
+
+        for (i, line) in s.trim_start_matches('\u{feff}').lines().enumerate() {
+            let error = |kind| ParseError { line: i + 1, kind };
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            if line.starts_with('[') {
+                in_events = line == "[Events]";
+                continue;
+            }
+            if !in_events {
+                continue;
+            }
+
+            let depth = line.len() - line.trim_start_matches([' ', '_']).len();
+            let fields = split_fields(&line[depth..]);
+            match (depth, &object, &group) {
+                (0, ..) if matches!(fields[0], "Sample" | "5") => {
+                    samples.push(parse_sample(&fields).map_err(error)?);
+                    object = Object::Skipped;
+                    group = Group::None;
+                }
+                (0, ..) => {
+                    object = match parse_object(&fields).map_err(error)? {
+                        Some(sprite) => {
+                            sprites.push(sprite);
+                            Object::Sprite
+                        }
+                        None => Object::Skipped,
+                    };
+                    group = Group::None;
+                }
+                (_, Object::Skipped, _) => {}
+                (_, Object::None, _) => return Err(error(ParseErrorKind::OrphanCommand)),
+                (1, Object::Sprite, _) => {
+                    let sprite = &mut sprites.last_mut().unwrap().1;
+                    group = match fields[0] {
+                        "L" => {
+                            let start_time = parse_field(&fields, 1).map_err(error)?;
+                            let loop_count = parse_field(&fields, 2).map_err(error)?;
+                            sprite.loop_(start_time, loop_count);
+                            Group::Loop
+                        }
+                        "T" => match parse_trigger_type(field(&fields, 1).map_err(error)?) {
+                            Some(trigger_type) => {
+                                let start_time = parse_field(&fields, 2).map_err(error)?;
+                                let end_time = parse_field(&fields, 3).map_err(error)?;
+                                sprite.trigger_(trigger_type, start_time, end_time);
+                                Group::Trigger
+                            }
+                            None => {
+                                sprite.push_raw(line);
+                                Group::Raw
+                            }
+                        },
+                        command if !is_known_command(command) => {
+                            sprite.push_raw(line);
+                            Group::None
+                        }
+                        _ => {
+                            for event in parse_command(&fields).map_err(error)? {
+                                push_event!(sprite, event);
+                            }
+                            Group::None
+                        }
+                    };
+                }
+                (_, Object::Sprite, Group::None) => {
+                    return Err(error(ParseErrorKind::OrphanCommand))
+                }
+                (_, Object::Sprite, Group::Raw) => sprites.last_mut().unwrap().1.push_raw(line),
+                (_, Object::Sprite, Group::Loop) if !is_known_command(fields[0]) => {
+                    let mut loop_ = sprites.last_mut().unwrap().1.last_loop().unwrap();
+                    loop_.push_raw(line);
+                }
+                (_, Object::Sprite, Group::Trigger) if !is_known_command(fields[0]) => {
+                    let mut trigger = sprites.last_mut().unwrap().1.last_trigger().unwrap();
+                    trigger.push_raw(line);
+                }
+                (_, Object::Sprite, Group::Loop) => {
+                    let events = parse_command(&fields).map_err(error)?;
+                    let mut loop_ = sprites.last_mut().unwrap().1.last_loop().unwrap();
+                    for event in events {
+                        push_event!(loop_, event);
+                    }
+                }
+                (_, Object::Sprite, Group::Trigger) => {
+                    let events = parse_command(&fields).map_err(error)?;
+                    let mut trigger = sprites.last_mut().unwrap().1.last_trigger().unwrap();
+                    for event in events {
+                        push_event!(trigger, event);
+                    }
+                }
+            }
+        }
+
+        let mut modules = [
+            Module::new(Layer::Background),
+            Module::new(Layer::Fail),
+            Module::new(Layer::Pass),
+            Module::new(Layer::Foreground),
+            Module::new(Layer::Overlay),
+        ];
+        for (layer, sprite) in sprites {
+            modules[layer.index() as usize].push(sprite);
+        }
+        let mut sb = Storyboard::new();
+        for module in modules {
+            if !module.is_empty() {
+                sb.push(module);
+            }
+        }
+        for sample in samples {
+            sb.push_sample(sample);
+        }
+        Ok(sb)
+    }
+}
+
+// Parses a single command line into an event of the kind `E`, the `line` of the errors being `1`
+//
+// The indentation of the line gives the depth of the event, one space or underscore writing the
+// commands of a sprite at the depth `0`.
+fn parse_event<E: TryFrom<AnyEvent, Error = AnyEvent>>(line: &str) -> Result<E, ParseError> {
+    let error = |kind| ParseError { line: 1, kind };
+    let line = line.trim_end();
+    let indentation = line.len() - line.trim_start_matches([' ', '_']).len();
+    let fields = split_fields(&line[indentation..]);
+
+    let mut events = parse_command(&fields).map_err(error)?;
+    if events.len() > 1 {
+        return Err(error(ParseErrorKind::TooManyFields));
+    }
+    let mut event = events.remove(0);
+    event.set_depth(indentation.saturating_sub(1));
+    E::try_from(event).map_err(|_| error(ParseErrorKind::UnknownCommand(fields[0].to_string())))
+}
+
+// Parses a single command line into an event
+macro_rules! event_from_str {
+    ($($kind:ident),*) => {
+        $(
+            #[doc = concat!(
+                "Parses a single command line into a [`", stringify!($kind), "`] event\n\n",
+                "The indentation of the line gives the depth of the event. A line holding more ",
+                "values than a start and an end one is rejected with ",
+                "[`ParseErrorKind::TooManyFields`], and a line of another command with ",
+                "[`ParseErrorKind::UnknownCommand`]. The `line` of the errors is always `1`."
+            )]
+            impl FromStr for $kind {
+                type Err = ParseError;
+
+                fn from_str(s: &str) -> Result<$kind, ParseError> {
+                    parse_event(s)
+                }
+            }
+        )*
+    };
+}
+
+event_from_str!(Move, MoveX, MoveY, Fade, Rotate, Scale, ScaleVec, Color, HFlip, VFlip, Additive);
+
+// Splits a line on its commas, except for the ones between quotes
+fn split_fields(line: &str) -> Vec<&str> {
+    let mut fields = Vec::new();
+    let (mut start, mut quoted) = (0, false);
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                fields.push(&line[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    fields.push(&line[start..]);
+    fields
+}
+
+fn field<'a>(fields: &[&'a str], index: usize) -> Result<&'a str, ParseErrorKind> {
+    fields
+        .get(index)
+        .copied()
+        .ok_or(ParseErrorKind::MissingField)
+}
+
+fn parse_field<T: FromStr>(fields: &[&str], index: usize) -> Result<T, ParseErrorKind> {
+    let field = field(fields, index)?;
+    field
+        .parse()
+        .map_err(|_| ParseErrorKind::InvalidField(field.to_string()))
+}
+
+fn parse_number(field: &str) -> Result<Number, ParseErrorKind> {
+    match (field.parse::<i32>(), field.parse::<f32>()) {
+        (Ok(value), _) => Ok(Number::Int(value)),
+        (_, Ok(value)) if value.is_finite() => Ok(Number::Float(value)),
+        _ => Err(ParseErrorKind::InvalidField(field.to_string())),
+    }
+}
+
+// A `Sprite` or an `Animation` along with its `Layer`, or `None` for the objects `osb` has no
+// representation for
+fn parse_object(fields: &[&str]) -> Result<Option<(Layer, Sprite)>, ParseErrorKind> {
+    let (animated, minimum) = match fields[0] {
+        "Sprite" | "4" => (false, 6),
+        "Animation" | "6" => (true, 8),
+        "0" | "1" | "2" | "3" | "Background" | "Video" | "Break" => return Ok(None),
+        command => return Err(ParseErrorKind::UnknownCommand(command.to_string())),
+    };
+    if fields.len() < minimum {
+        return Err(ParseErrorKind::MissingField);
+    }
+
+    let layer = parse_layer(fields[1])?;
+    let origin = parse_origin(fields[2])?;
+    let path = fields[3].trim_matches('"');
+    let pos = Vec2::from(parse_number(fields[4])?, parse_number(fields[5])?);
+    let sprite = if animated {
+        let frame_count = parse_field(fields, 6)?;
+        let frame_delay = parse_field(fields, 7)?;
+        let loop_type = match fields.get(8).copied() {
+            None | Some("LoopForever") | Some("0") => LoopType::LoopForever,
+            Some("LoopOnce") | Some("1") => LoopType::LoopOnce,
+            Some(field) => return Err(ParseErrorKind::InvalidField(field.to_string())),
+        };
+        Sprite::new((origin, path, pos, frame_count, frame_delay, loop_type))
+    } else {
+        Sprite::new((origin, path, pos))
+    };
+    Ok(Some((layer, sprite)))
+}
+
+// A `Sample,time,layer,"path",volume` line, the volume defaulting to 100
+fn parse_sample(fields: &[&str]) -> Result<Sample, ParseErrorKind> {
+    let time = parse_field(fields, 1)?;
+    let layer = parse_layer(field(fields, 2)?)?;
+    let path = field(fields, 3)?.trim_matches('"');
+    let volume = match fields.get(4) {
+        Some(_) => parse_field::<i32>(fields, 4)?.clamp(0, 100) as u8,
+        None => 100,
+    };
+    Ok(Sample::new(time, layer, path).with_volume(volume))
+}
+
+fn parse_layer(field: &str) -> Result<Layer, ParseErrorKind> {
+    match field {
+        "Background" | "0" => Ok(Layer::Background),
+        "Fail" | "1" => Ok(Layer::Fail),
+        "Pass" | "2" => Ok(Layer::Pass),
+        "Foreground" | "3" => Ok(Layer::Foreground),
+        "Overlay" | "4" => Ok(Layer::Overlay),
+        field => Err(ParseErrorKind::InvalidField(field.to_string())),
+    }
+}
+
+fn parse_origin(field: &str) -> Result<Origin, ParseErrorKind> {
+    match field {
+        "TopLeft" | "0" => Ok(Origin::TopLeft),
+        "TopCentre" | "1" => Ok(Origin::TopCentre),
+        "TopRight" | "2" => Ok(Origin::TopRight),
+        "CentreLeft" | "3" => Ok(Origin::CentreLeft),
+        "Centre" | "4" => Ok(Origin::Centre),
+        "CentreRight" | "5" => Ok(Origin::CentreRight),
+        "BottomLeft" | "6" => Ok(Origin::BottomLeft),
+        "BottomCentre" | "7" => Ok(Origin::BottomCentre),
+        "BottomRight" | "8" => Ok(Origin::BottomRight),
+        field => Err(ParseErrorKind::InvalidField(field.to_string())),
+    }
+}
+
+fn parse_trigger_type(field: &str) -> Option<TriggerType> {
+    match field {
+        "HitSound" => Some(TriggerType::HitSound),
+        "HitSoundNormal" => Some(TriggerType::HitSoundNormal),
+        "HitSoundSoft" => Some(TriggerType::HitSoundSoft),
+        "HitSoundDrum" => Some(TriggerType::HitSoundDrum),
+        "HitSoundWhistle" => Some(TriggerType::HitSoundWhistle),
+        "HitSoundFinish" => Some(TriggerType::HitSoundFinish),
+        "HitSoundClap" => Some(TriggerType::HitSoundClap),
+        "Passing" => Some(TriggerType::Passing),
+        "Failing" => Some(TriggerType::Failing),
+        _ => None,
+    }
+}
+
+// Whether `parse_command` knows the command `name`, the other ones being kept verbatim
+fn is_known_command(name: &str) -> bool {
+    matches!(name, "M" | "MX" | "MY" | "F" | "R" | "S" | "V" | "C" | "P")
+}
+
+// The events written by a command line, without its indentation
+fn parse_command(fields: &[&str]) -> Result<Vec<AnyEvent>, ParseErrorKind> {
+    let easing = field(fields, 1)?;
+    let easing = easing
+        .parse()
+        .ok()
+        .and_then(Easing::get_easing)
+        .ok_or_else(|| ParseErrorKind::InvalidField(easing.to_string()))?;
+    let start_time: i32 = parse_field(fields, 2)?;
+    let end_time = match field(fields, 3)? {
+        "" => start_time,
+        _ => parse_field(fields, 3)?,
+    };
+    let values = fields.get(4..).unwrap_or_default();
+
+    Ok(match fields[0] {
+        "M" => value_events(
+            easing,
+            start_time,
+            end_time,
+            parse_values(values, 2, |v| Vec2::from(v[0], v[1]))?,
+            Move::Static,
+            Move::Dynamic,
+        )?,
+        "MX" => number_events(
+            easing,
+            start_time,
+            end_time,
+            values,
+            MoveX::Static,
+            MoveX::Dynamic,
+        )?,
+        "MY" => number_events(
+            easing,
+            start_time,
+            end_time,
+            values,
+            MoveY::Static,
+            MoveY::Dynamic,
+        )?,
+        "F" => number_events(
+            easing,
+            start_time,
+            end_time,
+            values,
+            Fade::Static,
+            Fade::Dynamic,
+        )?,
+        "R" => number_events(
+            easing,
+            start_time,
+            end_time,
+            values,
+            Rotate::Static,
+            Rotate::Dynamic,
+        )?,
+        "S" => number_events(
+            easing,
+            start_time,
+            end_time,
+            values,
+            Scale::Static,
+            Scale::Dynamic,
+        )?,
+        "V" => value_events(
+            easing,
+            start_time,
+            end_time,
+            parse_values(values, 2, |v| Vec2::from(v[0], v[1]))?,
+            ScaleVec::Static,
+            ScaleVec::Dynamic,
+        )?,
+        "C" => {
+            // The channels range between 0 and 255, a float being rounded to the nearest one
+            let channel = |value: Number| math::round(value.as_f32()) as i32;
+            let colors = parse_values(values, 3, |v| {
+                utils::Color::from(channel(v[0]), channel(v[1]), channel(v[2]))
+            })?;
+            if colors.len() > 2 {
+                // The chain has to end within `i32` too, see `value_events`
+                end_time
+                    .checked_sub(start_time)
+                    .zip(i32::try_from(colors.len() - 1).ok())
+                    .and_then(|(duration, count)| duration.checked_mul(count))
+                    .and_then(|offset| start_time.checked_add(offset))
+                    .ok_or(ParseErrorKind::TimeOverflow)?;
+                vec![Color::Chained(0, easing, start_time, end_time, colors).into()]
+            } else {
+                value_events(
+                    easing,
+                    start_time,
+                    end_time,
+                    colors,
+                    Color::Static,
+                    Color::Dynamic,
+                )?
+            }
+        }
+        "P" => vec![match field(fields, 4)? {
+            "H" if fields[3].is_empty() => HFlip::Static(0, start_time).into(),
+            "H" => HFlip::Dynamic(0, easing, start_time, end_time).into(),
+            "V" if fields[3].is_empty() => VFlip::Static(0, start_time).into(),
+            "V" => VFlip::Dynamic(0, easing, start_time, end_time).into(),
+            "A" if fields[3].is_empty() => Additive::Static(0, start_time).into(),
+            "A" => Additive::Dynamic(0, easing, start_time, end_time).into(),
+            parameter => return Err(ParseErrorKind::InvalidField(parameter.to_string())),
+        }],
+        command => return Err(ParseErrorKind::UnknownCommand(command.to_string())),
+    })
+}
+
+// Parses `values` in sets of `arity` numbers, each set being turned into a value by `f`
+fn parse_values<V, F>(values: &[&str], arity: usize, f: F) -> Result<Vec<V>, ParseErrorKind>
+where
+    F: Fn(&[Number]) -> V,
+{
+    let numbers = values
+        .iter()
+        .map(|value| parse_number(value))
+        .collect::<Result<Vec<Number>, ParseErrorKind>>()?;
+    let sets = numbers.chunks_exact(arity);
+    if numbers.is_empty() || !sets.remainder().is_empty() {
+        return Err(ParseErrorKind::MissingField);
+    }
+    Ok(sets.map(f).collect())
+}
+
+fn number_events<E: Into<AnyEvent>>(
+    easing: Easing,
+    start_time: i32,
+    end_time: i32,
+    values: &[&str],
+    static_: fn(usize, i32, Number) -> E,
+    dynamic: fn(usize, Easing, i32, i32, Number, Number) -> E,
+) -> Result<Vec<AnyEvent>, ParseErrorKind> {
+    let values = parse_values(values, 1, |v| v[0])?;
+    value_events(easing, start_time, end_time, values, static_, dynamic)
+}
+
+// A single value is static unless it spans some time, and every following value is reached in
+// another event lasting as long as the first one
+fn value_events<V: Copy, E: Into<AnyEvent>>(
+    easing: Easing,
+    start_time: i32,
+    end_time: i32,
+    values: Vec<V>,
+    static_: fn(usize, i32, V) -> E,
+    dynamic: fn(usize, Easing, i32, i32, V, V) -> E,
+) -> Result<Vec<AnyEvent>, ParseErrorKind> {
+    Ok(match values[..] {
+        [value] if start_time == end_time => vec![static_(0, start_time, value).into()],
+        [value] => vec![dynamic(0, easing, start_time, end_time, value, value).into()],
+        _ => {
+            let duration = end_time
+                .checked_sub(start_time)
+                .ok_or(ParseErrorKind::TimeOverflow)?;
+            values
+                .windows(2)
+                .zip(0..)
+                .map(|(pair, i)| {
+                    // The times come straight from the file, a long line may go past `i32`
+                    let offset = duration.checked_mul(i);
+                    let start = offset.and_then(|offset| start_time.checked_add(offset));
+                    let end = offset.and_then(|offset| end_time.checked_add(offset));
+                    match (start, end) {
+                        (Some(start), Some(end)) => {
+                            Ok(dynamic(0, easing, start, end, pair[0], pair[1]).into())
+                        }
+                        _ => Err(ParseErrorKind::TimeOverflow),
+                    }
+                })
+                .collect::<Result<Vec<AnyEvent>, ParseErrorKind>>()?
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        utils::Color, Easing, Layer, LoopType, Module, ParseError, ParseErrorKind, Sample, Sprite,
+        Storyboard, TriggerType,
+    };
+
+    #[test]
+    fn round_trip() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite
+            .move_((Easing::QuadOut, 0, 1000, 0, 0, 320.5, 240))
+            .fade_((0, 0.5))
+            .color_keyframes(
+                Easing::Linear,
+                0,
+                500,
+                &[Color::red(), Color::white(), Color::blue()],
+            )
+            .hflip_((0, 1000))
+            .vflip_(1500)
+            .additive_((500, 1000));
+        sprite.loop_(1000, 4).rotate_((0, 500, 0, 2.5));
+        sprite
+            .trigger_(TriggerType::HitSoundClap, 0, 5000)
+            .scalevec_((0, 100, 1, 1, 1.5, 1.5));
+        module.push(sprite);
+        sb.push(module);
+
+        let mut module = Module::new(Layer::Background);
+        let mut animation = Sprite::new(("res/frame.png", 20, 100, LoopType::LoopOnce));
+        animation.movex_((0, 1000, 0, 640)).scale_((0, 2)).color_((
+            0,
+            1000,
+            Color::from(128, 64, 32),
+            Color::from(12, 200, 99),
+        ));
+        module.push(animation);
+        sb.push(module);
+        sb.push_sample(Sample::new(500, Layer::Pass, "sb/hit.wav").with_volume(40));
+
+        let parsed: Storyboard = sb.render().parse().unwrap();
+        assert_eq!(parsed.render(), sb.render());
+        assert!(parsed
+            .render()
+            .contains(" C,0,0,1000,128,64,32,12,200,99\n"));
+
+        let osb = "Sprite,Background,Centre,\"res/sprite.png\",320,240\n C,0,0,1000,128,64,32";
+        let parsed: Storyboard = osb.parse().unwrap();
+        assert!(parsed.render().contains("\n C,0,0,1000,128,64,32"));
+        assert!(!parsed.render().contains("255"));
+    }
+
+    #[test]
+    fn skipped_lines() {
+        let osb = "\u{feff}[General]\nSprite,wrong\n\n[Events]\n//Background and Video events\n\
+                   Video,0,\"video.mp4\"\n M,0,0,1000,0,0,320,240\n\
+                   Sample,0,0,\"hit.wav\",100\n\
+                   Sprite,Pass,TopLeft,\"res/sprite.png\",0,0\n\
+                   _F,0,0,1000,0,1,0\n\
+                   [Colours]\nCombo1 : 255,0,0\n";
+        let sb: Storyboard = osb.parse().unwrap();
+        let rendered = sb.render();
+        assert!(rendered.contains(
+            "//Storyboard Layer 2 (Pass)\nSprite,Pass,TopLeft,\"res/sprite.png\",0,0\n F,0,0,1000,0,1\n F,0,1000,2000,1,0\n"
+        ));
+        assert!(!rendered.contains("M,"));
+        assert!(!rendered.contains("Combo1"));
+        assert!(rendered.ends_with("//Storyboard Sound Samples\nSample,0,0,\"hit.wav\",100"));
+    }
+
+    #[test]
+    fn samples() {
+        let osb = "Sample,1000,Foreground,\"sb/a.wav\"\n\
+                   Sample,2000,0,\"sb/b.wav\",150\n\
+                   5,3000,1,\"sb/c.wav\",-5";
+        let sb: Storyboard = osb.parse().unwrap();
+        assert_eq!(
+            sb.samples(),
+            [
+                Sample::new(1000, Layer::Foreground, "sb/a.wav"),
+                Sample::new(2000, Layer::Background, "sb/b.wav"),
+                Sample::new(3000, Layer::Fail, "sb/c.wav").with_volume(0),
+            ]
+        );
+
+        let error = "Sample,0,Middle,\"a.wav\""
+            .parse::<Storyboard>()
+            .err()
+            .unwrap();
+        assert_eq!(error.kind, ParseErrorKind::InvalidField("Middle".into()));
+        let error = "Sample,0,0".parse::<Storyboard>().err().unwrap();
+        assert_eq!(error.kind, ParseErrorKind::MissingField);
+    }
+
+    #[test]
+    fn unknown_trigger_type() {
+        let osb = "Sprite,Background,Centre,\"res/sprite.png\",320,240\n\
+                   \x20T,HitSoundDrumWhistle,0,1000\n\
+                   \x20\x20F,0,0,100,1,0\n\
+                   \x20F,0,0,,1";
+        let sb: Storyboard = osb.parse().unwrap();
+        assert!(sb
+            .render()
+            .contains(" F,0,0,,1\n T,HitSoundDrumWhistle,0,1000\n  F,0,0,100,1,0\n"));
+    }
+
+    #[test]
+    fn unknown_commands() {
+        let osb = "Sprite,Background,Centre,\"res/sprite.png\",320,240\n\
+                   \x20Z,0,0,,1\n\
+                   \x20L,0,2\n\
+                   \x20\x20F,0,0,100,1,0\n\
+                   \x20\x20Z,0,0,100,2\n\
+                   \x20T,HitSoundClap,0,1000\n\
+                   \x20\x20Z,0,0,,3\n";
+        let sb: Storyboard = osb.parse().unwrap();
+        assert!(sb.render().contains(
+            " L,0,2\n  F,0,0,100,1,0\n  Z,0,0,100,2\n T,HitSoundClap,0,1000\n  Z,0,0,,3\n Z,0,0,,1\n"
+        ));
+
+        let parsed: Storyboard = sb.render().parse().unwrap();
+        assert_eq!(parsed.render(), sb.render());
+        assert_eq!(parsed.byte_len(), parsed.render().len());
+        assert_eq!(parsed.line_count(), parsed.render().lines().count());
+    }
+
+    #[test]
+    fn errors() {
+        let error = |osb: &str| osb.parse::<Storyboard>().err().unwrap();
+        let sprite = "Sprite,Background,Centre,\"res/sprite.png\",320,240\n";
+
+        assert_eq!(
+            error(" F,0,0,,1"),
+            ParseError {
+                line: 1,
+                kind: ParseErrorKind::OrphanCommand
+            }
+        );
+        assert_eq!(
+            error(&format!("{}  F,0,0,,1", sprite)).kind,
+            ParseErrorKind::OrphanCommand
+        );
+        assert_eq!(
+            error("Text,Background,Centre,\"a.png\",0,0").kind,
+            ParseErrorKind::UnknownCommand("Text".into())
+        );
+        assert_eq!(
+            error("Sprite,Middle,Centre,\"a.png\",0,0").kind,
+            ParseErrorKind::InvalidField("Middle".into())
+        );
+        assert_eq!(
+            error("Sprite,Background,Centre,\"a.png\",0").kind,
+            ParseErrorKind::MissingField
+        );
+        assert_eq!(
+            error(&format!("{} M,0,0,1000,0,0,320", sprite)).kind,
+            ParseErrorKind::MissingField
+        );
+        assert_eq!(
+            error(&format!("{} F,99,0,1000,0,1", sprite)).kind,
+            ParseErrorKind::InvalidField("99".into())
+        );
+        assert_eq!(
+            error(&format!("{} F,0,0,2000000000,0,1,0.5", sprite)).kind,
+            ParseErrorKind::TimeOverflow
+        );
+        assert_eq!(
+            error(&format!("{} F,0,-2000000000,2000000000,0,1", sprite)).kind,
+            ParseErrorKind::TimeOverflow
+        );
+        assert_eq!(
+            error(&format!(
+                "{} C,0,0,1000000000,0,0,0,1,1,1,2,2,2,3,3,3",
+                sprite
+            ))
+            .kind,
+            ParseErrorKind::TimeOverflow
+        );
+
+        let error = error(&format!("[Events]\n{}\n F,0,0,1000,0,one", sprite));
+        assert_eq!(error.line, 4);
+        assert_eq!(error.to_string(), "line 4: invalid field \"one\"");
+    }
+
+    #[test]
+    fn event_from_str() {
+        use crate::event::{self, Event, Fade, HFlip, Move, Scale};
+
+        let move_: Move = " M,0,0,1000,0,0,320,240".parse().unwrap();
+        assert_eq!(move_, (0, 1000, 0, 0, 320, 240).into());
+        assert_eq!(move_.to_line(), " M,0,0,1000,0,0,320,240");
+
+        let fade: Fade = "  F,0,500,,0.5".parse().unwrap();
+        assert_eq!(fade.to_line(), "  F,0,500,,0.5");
+        assert!(matches!(fade, Fade::Static(1, 500, _)));
+
+        let scale: Scale = "S,4,0,1000,1,2".parse().unwrap();
+        assert_eq!(scale.to_line(), " S,4,0,1000,1,2");
+
+        for line in [
+            " C,0,0,1000,255,0,0,0,0,255",
+            " C,0,0,1000,255,0,0,0,255,0,0,0,255",
+        ] {
+            assert_eq!(line.parse::<event::Color>().unwrap().to_line(), line);
+        }
+        assert_eq!(" P,0,0,,H".parse::<HFlip>().unwrap(), HFlip::Static(0, 0));
+
+        let error = |line: &str| line.parse::<Fade>().err().unwrap();
+        assert_eq!(
+            error(" F,0,0,1000,0,1,0").kind,
+            ParseErrorKind::TooManyFields
+        );
+        assert_eq!(
+            error(" M,0,0,1000,0,0,320,240").kind,
+            ParseErrorKind::UnknownCommand("M".into())
+        );
+        assert_eq!(error(" F,0,0").kind, ParseErrorKind::MissingField);
+        assert_eq!(error(" F,0,0,1000").kind, ParseErrorKind::MissingField);
+        assert_eq!(error(" F,0,0,1000,0,one").line, 1);
+    }
+}
@@ -1,28 +1,119 @@
-use crate::{Layer, Module};
-use std::io::{self, Write};
+use crate::{Easing, Layer, Lint, Module, Sample};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+};
 
 /// What defines a storyboard
 ///
 /// The usage of the struct `Storyboard` is a bit different from what you may be used to in other
 /// languages. We'd recommend you take a look at the struct [`Module`] to understand how
 /// `Storyboard`s are split in different `Module`s, improving modularity and speed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Storyboard {
     background_modules: Vec<Module>,
     fail_modules: Vec<Module>,
     pass_modules: Vec<Module>,
     foreground_modules: Vec<Module>,
     overlay_modules: Vec<Module>,
+    samples: Vec<Sample>,
+    explicit_loop_type: bool,
+    emit_empty_sections: bool,
 }
 
-fn modules_to_str(modules: &Vec<Module>) -> String {
-    modules
-        .iter()
-        .map(|m| m.output())
-        .collect::<Vec<String>>()
-        .join("")
+fn reversed_modules(modules: &[Module], total_duration: i32) -> Vec<Module> {
+    modules.iter().map(|m| m.reversed(total_duration)).collect()
 }
 
+const EVENTS_HEADER: &str = "[Events]\n";
+const VIDEO_HEADER: &str = "//Background and Video events\n";
+const SAMPLES_HEADER: &str = "//Storyboard Sound Samples\n";
+const LAYER_HEADERS: [&str; 5] = [
+    "//Storyboard Layer 0 (Background)\n",
+    "//Storyboard Layer 1 (Fail)\n",
+    "//Storyboard Layer 2 (Pass)\n",
+    "//Storyboard Layer 3 (Foreground)\n",
+    "//Storyboard Layer 4 (Overlay)\n",
+];
+
 impl Storyboard {
+    // The output of `modules`, rendered with the settings of our `Storyboard`
+    fn modules_to_str(&self, modules: &[Module]) -> String {
+        modules
+            .iter()
+            .map(|m| m.render(self.explicit_loop_type))
+            .collect::<Vec<String>>()
+            .join("")
+    }
+
+    // The lines of the samples, in the order they were pushed
+    fn samples_to_str(&self) -> String {
+        self.samples
+            .iter()
+            .map(|sample| format!("{}\n", sample))
+            .collect()
+    }
+
+    // The modules of each layer, in the order they are rendered
+    fn layers(&self) -> [&[Module]; 5] {
+        [
+            &self.background_modules,
+            &self.fail_modules,
+            &self.pass_modules,
+            &self.foreground_modules,
+            &self.overlay_modules,
+        ]
+    }
+
+    // Every section to render, as its header followed by its contents, the empty ones being left
+    // out unless `emit_empty_sections` is set
+    fn sections(&self) -> Vec<(&'static str, String)> {
+        let mut sections = vec![(VIDEO_HEADER, String::new())];
+        sections.extend(Vec::from(self.layer_sections()));
+        sections.push((SAMPLES_HEADER, self.samples_to_str()));
+        sections.retain(|(_, contents)| self.emit_empty_sections || !contents.is_empty());
+        sections
+    }
+
+    // The pieces of text making up the rendered `Storyboard`, which doesn't end with a newline
+    fn pieces<'a>(sections: &'a [(&'static str, String)]) -> Vec<&'a str> {
+        let mut pieces = vec![EVENTS_HEADER];
+        for (header, contents) in sections {
+            pieces.push(header);
+            pieces.push(contents);
+        }
+        while let Some(last) = pieces.pop() {
+            if !last.is_empty() {
+                pieces.push(last.strip_suffix('\n').unwrap_or(last));
+                break;
+            }
+        }
+        pieces
+    }
+
+    // The header of each layer followed by the output of its modules
+    fn layer_sections(&self) -> [(&'static str, String); 5] {
+        [
+            (
+                LAYER_HEADERS[0],
+                self.modules_to_str(&self.background_modules),
+            ),
+            (LAYER_HEADERS[1], self.modules_to_str(&self.fail_modules)),
+            (LAYER_HEADERS[2], self.modules_to_str(&self.pass_modules)),
+            (
+                LAYER_HEADERS[3],
+                self.modules_to_str(&self.foreground_modules),
+            ),
+            (LAYER_HEADERS[4], self.modules_to_str(&self.overlay_modules)),
+        ]
+    }
+
     /// Initializes a `Storyboard`
     pub fn new() -> Self {
         Self {
@@ -31,9 +122,48 @@ impl Storyboard {
             pass_modules: vec![],
             foreground_modules: vec![],
             overlay_modules: vec![],
+            samples: vec![],
+            explicit_loop_type: false,
+            emit_empty_sections: true,
         }
     }
 
+    /// Sets whether the comments heading the sections without contents are rendered
+    ///
+    /// They are rendered by default. Leaving them out gives the leanest output, which is what
+    /// minimizers are after.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::Storyboard;
+    /// let mut sb = Storyboard::new();
+    /// sb.set_emit_empty_sections(false);
+    /// assert_eq!(sb.render(), "[Events]");
+    /// ```
+    pub fn set_emit_empty_sections(&mut self, emit_empty_sections: bool) {
+        self.emit_empty_sections = emit_empty_sections;
+    }
+
+    /// Sets whether animations looping forever are rendered with an explicit `LoopForever`
+    ///
+    /// `LoopForever` being the default loop type of osu!, it is left out by default. Some
+    /// third-party tools are stricter and expect it to always be written out.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, LoopType, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new(("res/sprite.png", 20, 100, LoopType::LoopForever)));
+    /// sb.push(module);
+    ///
+    /// sb.set_explicit_loop_type(true);
+    /// assert!(sb.render().contains(",20,100,LoopForever\n"));
+    /// ```
+    pub fn set_explicit_loop_type(&mut self, explicit_loop_type: bool) {
+        self.explicit_loop_type = explicit_loop_type;
+    }
+
     /// Adds a [`Module`] to our `Storyboard`
     ///
     /// Usage:
@@ -53,35 +183,675 @@ impl Storyboard {
         }
     }
 
+    /// Adds a [`Sample`] to our `Storyboard`
+    ///
+    /// `Sample`s are written under the `//Storyboard Sound Samples` comment, in the order they were
+    /// pushed.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Sample, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// sb.push_sample(Sample::new(1000, Layer::Background, "sb/drum.wav").with_volume(70));
+    /// assert!(sb
+    ///     .render()
+    ///     .ends_with("//Storyboard Sound Samples\nSample,1000,0,\"sb/drum.wav\",70"));
+    /// ```
+    pub fn push_sample(&mut self, sample: Sample) {
+        self.samples.push(sample);
+    }
+
+    /// Returns the [`Sample`]s of our `Storyboard`, in the order they were pushed
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    /// Moves the [`Module`]s and [`Sample`]s of `other` to the end of the ones of our
+    /// `Storyboard`, which comes handy when sections of a map are built as separate `Storyboard`s
+    ///
+    /// Each `Module` stays in its layer and the order within each layer is preserved, the
+    /// `Module`s of `other` drawing above ours. The settings of our `Storyboard`, such as
+    /// [`Storyboard::set_explicit_loop_type`], are kept.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new("res/intro.png"));
+    /// sb.push(module);
+    ///
+    /// let mut chorus = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new("res/chorus.png"));
+    /// chorus.push(module);
+    ///
+    /// sb.merge(chorus);
+    /// assert!(sb
+    ///     .render()
+    ///     .contains("\"res/intro.png\",320,240\nSprite,Background,Centre,\"res/chorus.png\""));
+    /// ```
+    pub fn merge(&mut self, other: Storyboard) {
+        self.background_modules.extend(other.background_modules);
+        self.fail_modules.extend(other.fail_modules);
+        self.pass_modules.extend(other.pass_modules);
+        self.foreground_modules.extend(other.foreground_modules);
+        self.overlay_modules.extend(other.overlay_modules);
+        self.samples.extend(other.samples);
+    }
+
+    /// Returns a copy of our `Storyboard` played backwards, which comes handy when debugging
+    /// ghosting
+    ///
+    /// Every event happening at the timestamp `t` is moved to `total_duration - t`, its start and
+    /// end values are swapped and its easing is reversed.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240));
+    /// module.push(sprite);
+    /// sb.push(module);
+    ///
+    /// let reversed = sb.reversed(5000);
+    /// assert!(reversed.render().contains(" M,0,4000,5000,320,240,0,0\n"));
+    /// ```
+    pub fn reversed(&self, total_duration: i32) -> Storyboard {
+        Storyboard {
+            background_modules: reversed_modules(&self.background_modules, total_duration),
+            fail_modules: reversed_modules(&self.fail_modules, total_duration),
+            pass_modules: reversed_modules(&self.pass_modules, total_duration),
+            foreground_modules: reversed_modules(&self.foreground_modules, total_duration),
+            overlay_modules: reversed_modules(&self.overlay_modules, total_duration),
+            samples: self
+                .samples
+                .iter()
+                .map(|sample| Sample {
+                    time: total_duration - sample.time,
+                    ..sample.clone()
+                })
+                .collect(),
+            explicit_loop_type: self.explicit_loop_type,
+            emit_empty_sections: self.emit_empty_sections,
+        }
+    }
+
+    /// Removes, in each [`Module`], the [`Sprite`](crate::Sprite)s rendering exactly like an
+    /// earlier one
+    ///
+    /// Large generators sometimes emit the same `Sprite` many times. The first occurrence of each
+    /// `Sprite` is kept, preserving the order of the modules.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new("res/sprite.png"));
+    /// module.push(Sprite::new("res/sprite.png"));
+    /// sb.push(module);
+    /// sb.dedup_sprites();
+    /// ```
+    pub fn dedup_sprites(&mut self) {
+        for module in self
+            .background_modules
+            .iter_mut()
+            .chain(self.fail_modules.iter_mut())
+            .chain(self.pass_modules.iter_mut())
+            .chain(self.foreground_modules.iter_mut())
+            .chain(self.overlay_modules.iter_mut())
+        {
+            module.dedup_sprites();
+        }
+    }
+
+    /// Returns every [`Lint`] reported by the [`Sprite`](crate::Sprite)s of our `Storyboard`
+    ///
+    /// Each `Lint` comes with the [`Layer`] of its `Sprite` and the index of the `Sprite` within
+    /// that layer, counting the `Sprite`s of every [`Module`] of the layer in the order they are
+    /// rendered, the `Sprite`s of a `Module` being sorted by [depth](crate::Sprite::set_depth).
+    /// See [`Sprite::lint`](crate::Sprite::lint) for more details.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.set_clamp_to_playfield(false);
+    /// sprite.move_((0, 320, 240));
+    /// module.push(sprite);
+    /// sb.push(module);
+    ///
+    /// assert!(sb.validate().is_empty());
+    /// ```
+    pub fn validate(&self) -> Vec<(Layer, usize, Lint)> {
+        [
+            (Layer::Background, &self.background_modules),
+            (Layer::Fail, &self.fail_modules),
+            (Layer::Pass, &self.pass_modules),
+            (Layer::Foreground, &self.foreground_modules),
+            (Layer::Overlay, &self.overlay_modules),
+        ]
+        .iter()
+        .flat_map(|(layer, modules)| {
+            modules
+                .iter()
+                .flat_map(|module| module.sprites())
+                .enumerate()
+                .flat_map(move |(index, sprite)| {
+                    sprite
+                        .lint()
+                        .iter()
+                        .map(move |lint| (*layer, index, lint.clone()))
+                })
+        })
+        .collect()
+    }
+
+    /// Returns the audio lead-in, in milliseconds, needed for every event of our `Storyboard` to
+    /// be played, which is the value to give to `AudioLeadIn` in the `.osu` file
+    ///
+    /// osu! needs a lead-in as soon as an event starts before the timestamp 0.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.fade_((-800, 0, 0, 1));
+    /// module.push(sprite);
+    /// sb.push(module);
+    ///
+    /// assert_eq!(sb.required_lead_in(), 800);
+    /// ```
+    pub fn required_lead_in(&self) -> i32 {
+        self.layers()
+            .iter()
+            .flat_map(|modules| modules.iter())
+            .flat_map(|module| module.sprites())
+            .filter_map(|sprite| sprite.start_time())
+            .min()
+            .map_or(0, |start_time| (-start_time).max(0))
+    }
+
+    /// Returns the number of bytes each [`Layer`] contributes to the rendered `Storyboard`
+    ///
+    /// Only the output of the [`Module`]s is counted, the section headers are left out. This
+    /// comes handy to know which layer to cut events from when optimizing.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Foreground);
+    /// module.push(Sprite::new("res/sprite.png"));
+    /// sb.push(module);
+    ///
+    /// let breakdown = sb.layer_byte_breakdown();
+    /// assert_eq!(breakdown[3].0, Layer::Foreground);
+    /// assert!(breakdown[3].1 > 0);
+    /// ```
+    pub fn layer_byte_breakdown(&self) -> [(Layer, usize); 5] {
+        [
+            (
+                Layer::Background,
+                self.modules_to_str(&self.background_modules).len(),
+            ),
+            (Layer::Fail, self.modules_to_str(&self.fail_modules).len()),
+            (Layer::Pass, self.modules_to_str(&self.pass_modules).len()),
+            (
+                Layer::Foreground,
+                self.modules_to_str(&self.foreground_modules).len(),
+            ),
+            (
+                Layer::Overlay,
+                self.modules_to_str(&self.overlay_modules).len(),
+            ),
+        ]
+    }
+
+    /// Returns the number of [`Sprite`](crate::Sprite)s in our `Storyboard`, across every layer
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Foreground);
+    /// module.push(Sprite::new("res/sprite.png"));
+    /// sb.push(module);
+    /// let mut module = Module::new(Layer::Background);
+    /// module.push(Sprite::new("res/bg.png"));
+    /// sb.push(module);
+    ///
+    /// assert_eq!(sb.sprite_count(), 2);
+    /// ```
+    pub fn sprite_count(&self) -> usize {
+        self.layers()
+            .iter()
+            .flat_map(|modules| modules.iter())
+            .map(Module::len)
+            .sum()
+    }
+
+    /// Returns the number of events pushed to the [`Sprite`](crate::Sprite)s of our `Storyboard`,
+    /// across every layer
+    ///
+    /// The events of a loop are counted once, regardless of how many times the loop repeats them.
+    /// See [`Module::event_kind_counts`] for a count per kind of event.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Foreground);
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240)).fade_((0, 1));
+    /// module.push(sprite);
+    /// sb.push(module);
+    ///
+    /// assert_eq!(sb.event_count(), 2);
+    /// ```
+    pub fn event_count(&self) -> usize {
+        self.layers()
+            .iter()
+            .flat_map(|modules| modules.iter())
+            .map(|module| module.event_kind_counts().total())
+            .sum()
+    }
+
+    /// Returns the number of lines our `Storyboard` renders to, without rendering it
+    ///
+    /// This is much cheaper than counting the lines of [`Storyboard::render`], which comes handy
+    /// to estimate the size of huge storyboards. See [`Sprite::line_count`](crate::Sprite::line_count)
+    /// for more details.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::Storyboard;
+    /// let sb = Storyboard::new();
+    /// assert_eq!(sb.line_count(), sb.render().lines().count());
+    /// ```
+    pub fn line_count(&self) -> usize {
+        let layers = self
+            .layers()
+            .iter()
+            .map(|modules| modules.iter().map(Module::line_count).sum::<usize>())
+            .filter(|&lines| self.emit_empty_sections || lines > 0)
+            .map(|lines| lines + 1)
+            .sum::<usize>();
+        let video = if self.emit_empty_sections { 1 } else { 0 };
+        let samples = if self.emit_empty_sections || !self.samples.is_empty() {
+            1 + self.samples.len()
+        } else {
+            0
+        };
+        1 + video + layers + samples
+    }
+
+    /// Returns the number of bytes our `Storyboard` renders to, without rendering it as a whole
+    ///
+    /// Only one line is rendered at a time, which comes handy to show the size of huge storyboards
+    /// before writing them. See [`Sprite::byte_len`](crate::Sprite::byte_len) for more details.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::Storyboard;
+    /// let sb = Storyboard::new();
+    /// assert_eq!(sb.byte_len(), sb.render().len());
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        let layers = self
+            .layers()
+            .iter()
+            .zip(LAYER_HEADERS.iter())
+            .map(|(modules, header)| {
+                let len = modules
+                    .iter()
+                    .map(|module| module.rendered_len(self.explicit_loop_type))
+                    .sum::<usize>();
+                (header, len)
+            })
+            .filter(|&(_, len)| self.emit_empty_sections || len > 0)
+            .map(|(header, len)| header.len() + len)
+            .sum::<usize>();
+        let video = if self.emit_empty_sections {
+            VIDEO_HEADER.len()
+        } else {
+            0
+        };
+        let samples = if self.emit_empty_sections || !self.samples.is_empty() {
+            SAMPLES_HEADER.len()
+                + self
+                    .samples
+                    .iter()
+                    .map(|sample| sample.to_string().len() + 1)
+                    .sum::<usize>()
+        } else {
+            0
+        };
+        // The rendered `Storyboard` doesn't end with a newline
+        EVENTS_HEADER.len() + video + layers + samples - 1
+    }
+
     /// Prints our `Storyboard` to `stdout`
     ///
+    /// Requires the `std` feature
+    ///
     /// Usage:
     /// ```
     /// use osb::Storyboard;
     /// let mut sb = Storyboard::new();
     /// sb.print().unwrap();
     /// ```
+    #[cfg(feature = "std")]
     pub fn print(&mut self) -> io::Result<()> {
         let stdout = io::stdout();
         let mut stdout = stdout.lock();
 
-        stdout.write_all(b"[Events]\n")?;
-        stdout.write_all(b"//Background and Video events\n")?;
-        stdout.write_all(b"//Storyboard Layer 0 (Background)\n")?;
-        stdout.write_all(modules_to_str(&self.background_modules).as_bytes())?;
-        stdout.write_all(b"//Storyboard Layer 1 (Fail)\n")?;
-        stdout.write_all(modules_to_str(&self.fail_modules).as_bytes())?;
-        stdout.write_all(b"//Storyboard Layer 2 (Pass)\n")?;
-        stdout.write_all(modules_to_str(&self.pass_modules).as_bytes())?;
-        stdout.write_all(b"//Storyboard Layer 3 (Foreground)\n")?;
-        stdout.write_all(modules_to_str(&self.foreground_modules).as_bytes())?;
-        stdout.write_all(b"//Storyboard Layer 4 (Overlay)\n")?;
-        stdout.write_all(modules_to_str(&self.overlay_modules).as_bytes())?;
-        stdout.write_all(b"//Storyboard Sound Samples\n")
+        self.write_to(&mut stdout)?;
+        stdout.write_all(b"\n")
+    }
+
+    /// Writes our `Storyboard` to the file at `path`, replacing its contents if it already exists
+    ///
+    /// Requires the `std` feature
+    ///
+    /// Usage:
+    /// ```no_run
+    /// use osb::Storyboard;
+    /// let sb = Storyboard::new();
+    /// sb.save("storyboard.osb").unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to_file(path)
+    }
+
+    /// Writes our `Storyboard` to the file at `path` through a buffered writer, creating the file
+    /// or truncating it if it already exists
+    ///
+    /// The bytes written are exactly the ones [`Storyboard::print`] writes to `stdout`, without
+    /// rendering the whole `Storyboard` to a single buffer first. See [`Storyboard::write_to`].
+    ///
+    /// Requires the `std` feature
+    ///
+    /// Usage:
+    /// ```no_run
+    /// use osb::Storyboard;
+    /// let sb = Storyboard::new();
+    /// sb.write_to_file("storyboard.osb").unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(fs::File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.write_all(b"\n")?;
+        writer.flush()
     }
+
+    /// Writes our `Storyboard` to `w` line by line, so that nothing larger than a line is ever
+    /// rendered at once
+    ///
+    /// The bytes written are exactly the ones of [`Storyboard::render`], which doesn't end with a
+    /// newline. Wrapping `w` in a [`BufWriter`](std::io::BufWriter) is recommended.
+    ///
+    /// Requires the `std` feature
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::Storyboard;
+    /// let sb = Storyboard::new();
+    ///
+    /// let mut bytes = Vec::new();
+    /// sb.write_to(&mut bytes).unwrap();
+    /// assert_eq!(bytes, sb.render().into_bytes());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut w = NoTrailingNewline {
+            inner: w,
+            pending: false,
+        };
+        w.write_all(EVENTS_HEADER.as_bytes())?;
+        if self.emit_empty_sections {
+            w.write_all(VIDEO_HEADER.as_bytes())?;
+        }
+        for (modules, header) in self.layers().iter().zip(LAYER_HEADERS.iter()) {
+            if !self.emit_empty_sections && modules.iter().all(Module::is_empty) {
+                continue;
+            }
+            w.write_all(header.as_bytes())?;
+            for module in modules.iter() {
+                module.write_rendered(&mut w, self.explicit_loop_type)?;
+            }
+        }
+        if self.emit_empty_sections || !self.samples.is_empty() {
+            w.write_all(SAMPLES_HEADER.as_bytes())?;
+            for sample in &self.samples {
+                writeln!(w, "{}", sample)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders our `Storyboard` to a `String`, the same way its `Display` implementation does
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::Storyboard;
+    /// let sb = Storyboard::new();
+    /// assert!(sb.render().starts_with("[Events]\n"));
+    /// ```
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    /// Renders our `Storyboard` in a human-readable format meant for debugging
+    ///
+    /// Easings are written as their names instead of their ids and every command is followed by a
+    /// comment naming it. **Warning**: osu! can't load this format.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::{Easing, Layer, Module, Sprite, Storyboard};
+    /// let mut sb = Storyboard::new();
+    /// let mut module = Module::new(Layer::Background);
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((Easing::QuadOut, 0, 1000, 0, 0, 320, 240));
+    /// module.push(sprite);
+    /// sb.push(module);
+    ///
+    /// assert!(sb
+    ///     .render_debug()
+    ///     .contains(" M,QuadOut,0,1000,0,0,320,240 // Move\n"));
+    /// ```
+    pub fn render_debug(&self) -> String {
+        self.render()
+            .split('\n')
+            .map(debug_line)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Renders our `Storyboard` to bytes, sizing the output up front with
+    /// [`Storyboard::byte_len`]
+    ///
+    /// This is the byte counterpart of [`Storyboard::render`]. Each sprite is rendered straight
+    /// into the output, so that the storyboard is never held twice in memory.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::Storyboard;
+    /// let sb = Storyboard::new();
+    /// assert_eq!(sb.render_bytes(), sb.render().into_bytes());
+    /// ```
+    pub fn render_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_len());
+        // The last newline of a piece is only written once another piece follows, as in
+        // `NoTrailingNewline`
+        let mut pending = false;
+        let mut push = |piece: &str| {
+            if piece.is_empty() {
+                return;
+            }
+            if pending {
+                bytes.push(b'\n');
+            }
+            let stripped = piece.strip_suffix('\n');
+            pending = stripped.is_some();
+            bytes.extend_from_slice(stripped.unwrap_or(piece).as_bytes());
+        };
+
+        push(EVENTS_HEADER);
+        if self.emit_empty_sections {
+            push(VIDEO_HEADER);
+        }
+        for (modules, header) in self.layers().iter().zip(LAYER_HEADERS.iter()) {
+            if !self.emit_empty_sections && modules.iter().all(Module::is_empty) {
+                continue;
+            }
+            push(header);
+            for sprite in modules.iter().flat_map(|module| module.sprites()) {
+                push(&sprite.render(self.explicit_loop_type));
+            }
+        }
+        if self.emit_empty_sections || !self.samples.is_empty() {
+            push(SAMPLES_HEADER);
+            for sample in &self.samples {
+                push(&format!("{}\n", sample));
+            }
+        }
+        bytes
+    }
+}
+
+// A writer holding back the last newline written to it, so that the rendered `Storyboard` doesn't
+// end with one
+#[cfg(feature = "std")]
+struct NoTrailingNewline<'a, W: Write> {
+    inner: &'a mut W,
+    pending: bool,
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> Write for NoTrailingNewline<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.pending {
+            self.inner.write_all(b"\n")?;
+            self.pending = false;
+        }
+        match buf.split_last() {
+            Some((b'\n', rest)) => {
+                self.inner.write_all(rest)?;
+                self.pending = true;
+            }
+            _ => self.inner.write_all(buf)?,
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// The line of a command with its easing id replaced by its name and followed by a comment naming
+// the command, any other line being left as is
+fn debug_line(line: &str) -> String {
+    let command = line.trim_start();
+    if command.len() == line.len() {
+        return String::from(line);
+    }
+    let mut fields = command.split(',').collect::<Vec<&str>>();
+    let name = match (fields[0], fields.last()) {
+        ("M", _) => "Move",
+        ("MX", _) => "MoveX",
+        ("MY", _) => "MoveY",
+        ("F", _) => "Fade",
+        ("R", _) => "Rotate",
+        ("S", _) => "Scale",
+        ("V", _) => "ScaleVec",
+        ("C", _) => "Color",
+        ("P", Some(&"H")) => "HFlip",
+        ("P", Some(&"V")) => "VFlip",
+        ("P", Some(&"A")) => "Additive",
+        ("L", _) => return format!("{} // Loop", line),
+        ("T", _) => return format!("{} // Trigger", line),
+        _ => return String::from(line),
+    };
+    let easing = fields
+        .get(1)
+        .and_then(|id| id.parse().ok())
+        .and_then(Easing::get_easing);
+    if let Some(easing) = easing {
+        fields[1] = easing.name();
+    }
+    format!(
+        "{}{} // {}",
+        &line[..line.len() - command.len()],
+        fields.join(","),
+        name
+    )
+}
+
+/// Returns the filename osu! expects for the storyboard of a mapset, `Artist - Title (Creator).osb`
+///
+/// The characters that can't be part of a filename, such as `:` or `/`, are stripped the same way
+/// osu! does.
+///
+/// Usage:
+/// ```
+/// use osb::osb_filename;
+/// assert_eq!(
+///     osb_filename("Camellia", "Exit This Earth's Atomosphere", "Ballasi"),
+///     "Camellia - Exit This Earth's Atomosphere (Ballasi).osb"
+/// );
+/// ```
+pub fn osb_filename(artist: &str, title: &str, creator: &str) -> String {
+    format!("{} - {} ({}).osb", artist, title, creator)
+        .chars()
+        .filter(|c| {
+            !c.is_control() && !matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*')
+        })
+        .collect()
+}
+
+/// Writes each `Storyboard` to the file of its name in the directory `dir`, creating the
+/// directory if needed
+///
+/// This comes handy for mapsets holding a storyboard per difficulty. See [`Storyboard::save`].
+///
+/// Requires the `std` feature
+///
+/// Usage:
+/// ```no_run
+/// use osb::{save_all, Storyboard};
+/// use std::path::Path;
+///
+/// let storyboards = vec![
+///     (String::from("Artist - Title (Mapper) [Easy].osb"), Storyboard::new()),
+///     (String::from("Artist - Title (Mapper) [Hard].osb"), Storyboard::new()),
+/// ];
+/// save_all(&storyboards, Path::new("mapset")).unwrap();
+/// ```
+#[cfg(feature = "std")]
+pub fn save_all(storyboards: &[(String, Storyboard)], dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (filename, storyboard) in storyboards {
+        storyboard.save(dir.join(filename))?;
+    }
+    Ok(())
 }
 
-use std::fmt;
+use core::fmt;
 
 impl fmt::Display for Storyboard {
     /// Formats the value using the given formatter
@@ -93,25 +863,19 @@ impl fmt::Display for Storyboard {
     /// println!("{}", sb);
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "[Events]")?;
-        writeln!(f, "//Background and Video events")?;
-        writeln!(f, "//Storyboard Layer 0 (Background)")?;
-        write!(f, "{}", modules_to_str(&self.background_modules))?;
-        writeln!(f, "//Storyboard Layer 1 (Fail)")?;
-        write!(f, "{}", modules_to_str(&self.fail_modules))?;
-        writeln!(f, "//Storyboard Layer 2 (Pass)")?;
-        write!(f, "{}", modules_to_str(&self.pass_modules))?;
-        writeln!(f, "//Storyboard Layer 3 (Foreground)")?;
-        write!(f, "{}", modules_to_str(&self.foreground_modules))?;
-        writeln!(f, "//Storyboard Layer 4 (Overlay)")?;
-        write!(f, "{}", modules_to_str(&self.overlay_modules))?;
-        write!(f, "//Storyboard Sound Samples")
+        for piece in Self::pieces(&self.sections()) {
+            write!(f, "{}", piece)?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Layer, Module, Storyboard};
+    use crate::{
+        utils::{Color, Vec2},
+        Easing, Layer, Lint, LoopType, Module, Sample, Sprite, Storyboard,
+    };
 
     #[test]
     fn modules() {
@@ -125,4 +889,524 @@ mod tests {
         sb.push(foreground_module);
         sb.push(overlay_module);
     }
+
+    #[test]
+    fn reversed() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Background);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.move_((Easing::QuadIn, 0, 1000, 0, 0, 320, 240));
+        module.push(sprite);
+        sb.push(module);
+
+        assert_eq!(
+            format!("{}", sb.reversed(5000)),
+            "[Events]
+//Background and Video events
+//Storyboard Layer 0 (Background)
+Sprite,Background,Centre,\"res/sprite.png\",320,240
+ M,4,4000,5000,320,240,0,0
+//Storyboard Layer 1 (Fail)
+//Storyboard Layer 2 (Pass)
+//Storyboard Layer 3 (Foreground)
+//Storyboard Layer 4 (Overlay)
+//Storyboard Sound Samples"
+        );
+    }
+
+    #[test]
+    fn dedup_sprites() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Background);
+        for _ in 0..3 {
+            let mut sprite = Sprite::new("res/sprite.png");
+            sprite.move_((0, 1000, 0, 0, 320, 240));
+            sprite.fade_((0, 1000, 0, 1));
+            module.push(sprite);
+        }
+        let mut other = Sprite::new("res/other.png");
+        other.move_((0, 1000, 0, 0, 320, 240));
+        module.push(other);
+        sb.push(module);
+
+        sb.dedup_sprites();
+        let output = format!("{}", sb);
+        assert_eq!(output.matches("res/sprite.png").count(), 1);
+        assert_eq!(output.matches("res/other.png").count(), 1);
+    }
+
+    #[test]
+    fn layer_byte_breakdown() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Background);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.move_((0, 1000, 0, 0, 320, 240));
+        module.push(sprite);
+        sb.push(module);
+        sb.push(Module::new(Layer::Overlay));
+
+        let breakdown = sb.layer_byte_breakdown();
+        assert_eq!(breakdown[0].0, Layer::Background);
+        assert!(breakdown[0].1 > 0);
+        for (_, bytes) in &breakdown[1..] {
+            assert_eq!(*bytes, 0);
+        }
+    }
+
+    // Only relies on `alloc`, run with `--no-default-features` to check the `no_std` core
+    #[test]
+    fn render_without_io() {
+        use alloc::string::ToString;
+
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.fade_((0, 1000, 0, 1));
+        module.push(sprite);
+        sb.push(module);
+
+        let output = sb.to_string();
+        assert!(output.contains(
+            "//Storyboard Layer 3 (Foreground)\nSprite,Foreground,Centre,\"res/sprite.png\",320,240\n F,0,0,1000,0,1\n"
+        ));
+    }
+
+    #[test]
+    fn render_bytes() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Pass);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.move_((0, 1000, 0, 0, 320, 240));
+        module.push(sprite);
+        sb.push(module);
+
+        let bytes = sb.render_bytes();
+        assert_eq!(bytes, sb.render().into_bytes());
+        assert_eq!(bytes.len(), bytes.capacity());
+
+        sb.push_sample(Sample::new(500, Layer::Pass, "sb/hit.wav"));
+        let bytes = sb.render_bytes();
+        assert_eq!(bytes, sb.render().into_bytes());
+        assert_eq!(bytes.len(), bytes.capacity());
+    }
+
+    #[test]
+    fn line_count() {
+        let mut sb = Storyboard::new();
+        assert_eq!(sb.line_count(), sb.render().lines().count());
+
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new("res/sprite.png").with_color(Color::red());
+        sprite
+            .move_((0, 1000, 0, 0, 320, 240))
+            .fade_((500, 1500, 0, 1))
+            .rotate_((200, 800, 0, 1));
+        module.push(sprite);
+        let mut sprite = Sprite::new("res/sprite.png");
+        for cycle in 0..3 {
+            let time = cycle * 1000;
+            sprite
+                .fade_((time, time + 500, 0, 1))
+                .fade_((time + 500, time + 1000, 1, 0));
+        }
+        assert!(sprite.auto_loop(1000));
+        module.push(sprite);
+        sb.push(module);
+        sb.push(Module::new(Layer::Overlay));
+
+        assert_eq!(sb.line_count(), sb.render().lines().count());
+        assert_eq!(sb.line_count(), 17);
+        assert_eq!(sb.byte_len(), sb.render().len());
+    }
+
+    #[test]
+    fn byte_len() {
+        let mut sb = Storyboard::new();
+        assert_eq!(sb.byte_len(), sb.render().len());
+
+        let mut module = Module::new(Layer::Pass);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.move_((0, 1000, 0, 0, 320, 240)).fade_((0, 0.5));
+        module.push(sprite);
+        sb.push(module);
+        sb.push_sample(Sample::new(1000, Layer::Background, "sb/drum.wav"));
+        assert_eq!(sb.byte_len(), sb.render().len());
+
+        sb.set_emit_empty_sections(false);
+        assert_eq!(sb.byte_len(), sb.render().len());
+    }
+
+    #[test]
+    fn sprite_and_event_count() {
+        let mut sb = Storyboard::new();
+        assert_eq!(sb.sprite_count(), 0);
+        assert_eq!(sb.event_count(), 0);
+
+        let mut module = Module::new(Layer::Background);
+        let mut sprite = Sprite::new("res/bg.png");
+        sprite
+            .fade_((0, 1000, 0, 1))
+            .fade_((500, 1500, 1, 0))
+            .scale_((0, 1));
+        module.push(sprite);
+        module.push(Sprite::new("res/empty.png"));
+        sb.push(module);
+
+        let mut module = Module::new(Layer::Overlay);
+        let mut sprite = Sprite::new("res/sprite.png");
+        let mut loop_ = sprite.loop_(0, 3);
+        loop_.move_((0, 100, 0, 0, 320, 240));
+        loop_.rotate_((0, 100, 0, 1));
+        module.push(sprite);
+        sb.push(module);
+
+        assert_eq!(sb.sprite_count(), 3);
+        assert_eq!(sb.event_count(), 5);
+    }
+
+    #[test]
+    fn merge() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Foreground);
+        module.push(Sprite::new("res/a.png"));
+        sb.push(module);
+        sb.push_sample(Sample::new(0, Layer::Background, "sb/a.wav"));
+
+        let mut other = Storyboard::new();
+        let mut module = Module::new(Layer::Foreground);
+        module.push(Sprite::new("res/b.png"));
+        other.push(module);
+        let mut module = Module::new(Layer::Fail);
+        module.push(Sprite::new("res/c.png"));
+        other.push(module);
+        other.push_sample(Sample::new(1000, Layer::Background, "sb/b.wav"));
+        other.set_emit_empty_sections(false);
+
+        sb.merge(other);
+        assert_eq!(
+            sb.render(),
+            "[Events]
+//Background and Video events
+//Storyboard Layer 0 (Background)
+//Storyboard Layer 1 (Fail)
+Sprite,Fail,Centre,\"res/c.png\",320,240
+//Storyboard Layer 2 (Pass)
+//Storyboard Layer 3 (Foreground)
+Sprite,Foreground,Centre,\"res/a.png\",320,240
+Sprite,Foreground,Centre,\"res/b.png\",320,240
+//Storyboard Layer 4 (Overlay)
+//Storyboard Sound Samples
+Sample,0,0,\"sb/a.wav\",100
+Sample,1000,0,\"sb/b.wav\",100"
+        );
+    }
+
+    #[test]
+    fn explicit_loop_type() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Background);
+        module.push(Sprite::new((
+            "res/sprite.png",
+            20,
+            100,
+            LoopType::LoopForever,
+        )));
+        module.push(Sprite::new(("res/sprite.png", 20, 100, LoopType::LoopOnce)));
+        sb.push(module);
+
+        let implicit = sb.render();
+        assert!(
+            implicit.contains("Animation,Background,Centre,\"res/sprite.png\",320,240,20,100\n")
+        );
+        assert!(implicit.contains(",20,100,LoopOnce\n"));
+
+        sb.set_explicit_loop_type(true);
+        let explicit = sb.render();
+        assert!(explicit.contains(
+            "Animation,Background,Centre,\"res/sprite.png\",320,240,20,100,LoopForever\n"
+        ));
+        assert!(explicit.contains(",20,100,LoopOnce\n"));
+        assert_eq!(sb.line_count(), explicit.lines().count());
+        assert_eq!(sb.byte_len(), explicit.len());
+    }
+
+    #[test]
+    fn render_debug() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Background);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite
+            .move_((Easing::QuadOut, 0, 1000, 0, 0, 320, 240))
+            .hflip_((0, 1000));
+        module.push(sprite);
+        sb.push(module);
+
+        let debug = sb.render_debug();
+        assert!(debug.contains("Sprite,Background,Centre,\"res/sprite.png\",320,240\n"));
+        assert!(debug.contains(" M,QuadOut,0,1000,0,0,320,240 // Move\n"));
+        assert!(debug.contains(" P,Linear,0,1000,H // HFlip\n"));
+        assert!(!debug.contains(" M,4,"));
+        assert!(debug.ends_with("//Storyboard Sound Samples"));
+    }
+
+    #[test]
+    fn required_lead_in() {
+        let mut sb = Storyboard::new();
+        assert_eq!(sb.required_lead_in(), 0);
+
+        let mut module = Module::new(Layer::Background);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.fade_((200, 1000, 0, 1));
+        module.push(sprite);
+        sb.push(module);
+        assert_eq!(sb.required_lead_in(), 0);
+
+        let mut module = Module::new(Layer::Overlay);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.move_((-800, 0, 0, 0, 320, 240));
+        module.push(sprite);
+        sb.push(module);
+        assert_eq!(sb.required_lead_in(), 800);
+    }
+
+    #[test]
+    fn overlay() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Overlay);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.fade_((0, 1000, 0, 1));
+        module.push(sprite);
+        sb.push(module);
+
+        assert_eq!(
+            sb.render(),
+            "[Events]
+//Background and Video events
+//Storyboard Layer 0 (Background)
+//Storyboard Layer 1 (Fail)
+//Storyboard Layer 2 (Pass)
+//Storyboard Layer 3 (Foreground)
+//Storyboard Layer 4 (Overlay)
+Sprite,Overlay,Centre,\"res/sprite.png\",320,240
+ F,0,0,1000,0,1
+//Storyboard Sound Samples"
+        );
+    }
+
+    #[test]
+    fn samples() {
+        let mut sb = Storyboard::new();
+        sb.push_sample(Sample::new(2000, Layer::Foreground, "sb/b.wav").with_volume(150));
+        sb.push_sample(Sample::new(1000, Layer::Background, "sb/a.wav"));
+        assert!(sb.render().ends_with(
+            "//Storyboard Sound Samples\nSample,2000,3,\"sb/b.wav\",100\nSample,1000,0,\"sb/a.wav\",100"
+        ));
+        assert_eq!(sb.line_count(), sb.render().lines().count());
+
+        let reversed = sb.reversed(5000);
+        assert_eq!(reversed.samples()[0].time, 3000);
+        assert_eq!(reversed.samples()[1].time, 4000);
+
+        sb.set_emit_empty_sections(false);
+        assert_eq!(
+            sb.render(),
+            "[Events]
+//Storyboard Sound Samples
+Sample,2000,3,\"sb/b.wav\",100
+Sample,1000,0,\"sb/a.wav\",100"
+        );
+        assert_eq!(sb.line_count(), sb.render().lines().count());
+    }
+
+    #[test]
+    fn emit_empty_sections() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Background);
+        module.push(Sprite::new("res/sprite.png"));
+        sb.push(module);
+        sb.push(Module::new(Layer::Overlay));
+
+        let rendered = sb.render();
+        assert!(rendered.contains("//Storyboard Layer 4 (Overlay)\n"));
+        assert!(rendered.ends_with("\n//Storyboard Sound Samples"));
+
+        sb.set_emit_empty_sections(false);
+        assert_eq!(
+            sb.render(),
+            "[Events]
+//Storyboard Layer 0 (Background)
+Sprite,Background,Centre,\"res/sprite.png\",320,240"
+        );
+        assert_eq!(sb.render_bytes(), sb.render().into_bytes());
+        assert_eq!(sb.line_count(), sb.render().lines().count());
+    }
+
+    #[test]
+    fn validate() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Foreground);
+        module.push(Sprite::new("res/sprite.png"));
+        sb.push(module);
+
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.set_clamp_to_playfield(false);
+        sprite.move_((0, 320, 240));
+        module.push(sprite);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.set_clamp_to_playfield(false);
+        sprite.move_((0, 1000, 320, 240, 320, 600));
+        module.push(sprite);
+        sb.push(module);
+
+        assert_eq!(
+            sb.validate(),
+            vec![(
+                Layer::Foreground,
+                2,
+                Lint::OffScreen {
+                    time: 1000,
+                    pos: Vec2::from(320, 600)
+                }
+            )]
+        );
+
+        // A `Sprite` given a depth is reported where it renders, not where it was pushed
+        let mut module = Module::new(Layer::Foreground);
+        module.push(Sprite::new("res/sprite.png"));
+        module.push(Sprite::new("res/sprite.png"));
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.set_clamp_to_playfield(false);
+        sprite.set_depth(0);
+        sprite.move_((0, -400, 240));
+        module.push(sprite);
+        sb.push(module);
+
+        let lints = sb.validate();
+        assert_eq!(lints.len(), 2);
+        assert_eq!(
+            lints[1],
+            (
+                Layer::Foreground,
+                4,
+                Lint::OffScreen {
+                    time: 0,
+                    pos: Vec2::from(-400, 240)
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_to_file() {
+        let path =
+            std::env::temp_dir().join(format!("osb-write-to-file-{}.osb", std::process::id()));
+
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.move_((0, 1000, 0, 0, 320, 240));
+        module.push(sprite);
+        sb.push(module);
+
+        std::fs::write(
+            &path,
+            "previous contents that are longer than the storyboard itself",
+        )
+        .unwrap();
+        sb.write_to_file(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, format!("{}\n", sb));
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(sb
+            .write_to_file(std::env::temp_dir().join("osb-missing-dir").join("sb.osb"))
+            .is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_to() {
+        let written = |sb: &Storyboard| {
+            let mut bytes = Vec::new();
+            sb.write_to(&mut bytes).unwrap();
+            String::from_utf8(bytes).unwrap()
+        };
+
+        let mut sb = Storyboard::new();
+        assert_eq!(written(&sb), sb.render());
+
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new(("res/sprite.png", 20, 100, LoopType::LoopForever));
+        sprite
+            .move_((0, 1000, 0, 0, 320, 240))
+            .move_((0, 1000, 0, 0, 320, 240))
+            .fade_((0, 0.5));
+        sprite.loop_(1000, 2).scale_((0, 500, 1, 2));
+        sprite.push_raw(" X,0,0,1000,42");
+        module.push(sprite);
+        sb.push(module);
+        sb.push(Module::new(Layer::Overlay));
+        sb.set_explicit_loop_type(true);
+        assert_eq!(written(&sb), sb.render());
+
+        sb.set_emit_empty_sections(false);
+        assert_eq!(written(&sb), sb.render());
+
+        sb.push_sample(Sample::new(1000, Layer::Background, "sb/drum.wav"));
+        assert_eq!(written(&sb), sb.render());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn save_all() {
+        let dir = std::env::temp_dir().join(format!("osb-save-all-{}", std::process::id()));
+
+        let mut hard = Storyboard::new();
+        let mut module = Module::new(Layer::Background);
+        module.push(Sprite::new("res/sprite.png"));
+        hard.push(module);
+        let storyboards = vec![
+            (String::from("easy.osb"), Storyboard::new()),
+            (String::from("hard.osb"), hard),
+        ];
+
+        super::save_all(&storyboards, &dir.join("mapset")).unwrap();
+        for (filename, storyboard) in &storyboards {
+            let contents = std::fs::read_to_string(dir.join("mapset").join(filename)).unwrap();
+            assert_eq!(contents, storyboard.render() + "\n");
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut sb = Storyboard::new();
+        let mut module = Module::new(Layer::Foreground);
+        let mut sprite = Sprite::new("res/sprite.png");
+        sprite.move_((Easing::QuadIn, 0, 1000, 0, 0, 320, 240));
+        sprite.color_((500, Color::from(255, 0, 0)));
+        sprite.rotate_((0, 1000, 0, 1.5));
+        sprite.loop_(2000, 2).fade_((0, 500, 1, 0));
+        module.push(sprite);
+        sb.push(module);
+
+        let json = serde_json::to_string(&sb).unwrap();
+        let deserialized: Storyboard = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.render(), sb.render());
+    }
+
+    #[test]
+    fn osb_filename() {
+        assert_eq!(
+            super::osb_filename("AC/DC", "Re:Zero", "Ballasi"),
+            "ACDC - ReZero (Ballasi).osb"
+        );
+        assert_eq!(
+            super::osb_filename("a\\b", "\"What?\" <*|>", "c"),
+            "ab - What  (c).osb"
+        );
+    }
 }
@@ -1,11 +1,26 @@
+use crate::{math, ColorParseError};
+use alloc::format;
+use alloc::string::String;
+use core::ops::Mul;
+
 /// A color type
 ///
-/// Contains an `r`, `g` and `b` value that ranges between 0 and 255
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// Contains an `r`, `g`, `b` and `a` value that ranges between 0 and 255, `a` being the alpha
+/// channel. osu! has no alpha channel in its [`Color`](crate::event::Color) events, which only
+/// write the `r`, `g` and `b` values, so the alpha channel is left to callers building their own
+/// [`Fade`](crate::event::Fade) logic and defaults to 255.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     r: i32,
     g: i32,
     b: i32,
+    a: i32,
+}
+
+// Clamps a channel between 0 and 255
+fn clamp_channel(value: i32) -> i32 {
+    value.clamp(0, 255)
 }
 
 impl Color {
@@ -17,26 +32,180 @@ impl Color {
     /// let my_color = Color::from(42, 42, 42);
     /// ```
     pub fn from(r: i32, g: i32, b: i32) -> Self {
-        let (mut r, mut g, mut b) = (r, g, b);
-        if r < 0 {
-            r = 0;
-        } else if r > 255 {
-            r = 255;
+        Color::from_rgba(r, g, b, 255)
+    }
+
+    /// Allows you to create a `Color` with an alpha channel
+    ///
+    /// Every channel is clamped between 0 and 255, like in [`Color::from`].
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Color;
+    /// let my_color = Color::from_rgba(42, 42, 42, 128);
+    /// assert_eq!(my_color.a(), 128);
+    /// assert_eq!(Color::from_rgba(0, 0, 0, 300).a(), 255);
+    /// ```
+    pub fn from_rgba(r: i32, g: i32, b: i32, a: i32) -> Self {
+        Self {
+            r: clamp_channel(r),
+            g: clamp_channel(g),
+            b: clamp_channel(b),
+            a: clamp_channel(a),
         }
+    }
+
+    /// Allows you to create a `Color` from channels ranging between 0 and 1
+    ///
+    /// Each channel is multiplied by 255 and rounded half away from zero, `0.5` giving `128`, then
+    /// clamped like in [`Color::from`].
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Color;
+    /// assert_eq!(Color::from_f32(1., 0.5, 0.), Color::from(255, 128, 0));
+    /// ```
+    pub fn from_f32(r: f32, g: f32, b: f32) -> Self {
+        let channel = |value: f32| math::round(value * 255.) as i32;
+        Color::from(channel(r), channel(g), channel(b))
+    }
 
-        if g < 0 {
-            g = 0;
-        } else if g > 255 {
-            g = 255;
+    /// Allows you to create a `Color` from its hue, saturation and value
+    ///
+    /// `h` is in degrees, wrapping around every 360 degrees, while `s` and `v` range between 0 and
+    /// 1. The channels are rounded like in [`Color::from_f32`].
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Color;
+    /// assert_eq!(Color::from_hsv(0., 1., 1.), Color::red());
+    /// assert_eq!(Color::from_hsv(120., 1., 1.), Color::green());
+    /// assert_eq!(Color::from_hsv(30., 1., 1.), Color::from(255, 128, 0));
+    /// ```
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        let (s, v) = (s.clamp(0., 1.), v.clamp(0., 1.));
+        let mut h = (h % 360.) / 60.;
+        if h < 0. {
+            h += 6.;
         }
 
-        if b < 0 {
-            b = 0;
-        } else if b > 255 {
-            b = 255;
+        let chroma = v * s;
+        let distance = h % 2. - 1.;
+        let x = chroma * (1. - if distance < 0. { -distance } else { distance });
+        let (r, g, b) = match h as i32 {
+            0 => (chroma, x, 0.),
+            1 => (x, chroma, 0.),
+            2 => (0., chroma, x),
+            3 => (0., x, chroma),
+            4 => (x, 0., chroma),
+            _ => (chroma, 0., x),
+        };
+        let m = v - chroma;
+        Color::from_f32(r + m, g + m, b + m)
+    }
+
+    /// Returns the hue, saturation and value of a `Color`
+    ///
+    /// The hue is in degrees between 0 and 360, and is 0 for shades of gray. The saturation and
+    /// value range between 0 and 1. The alpha channel is ignored.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Color;
+    /// assert_eq!(Color::blue().to_hsv(), (240., 1., 1.));
+    /// assert_eq!(Color::from(255, 255, 255).to_hsv(), (0., 0., 1.));
+    /// ```
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (r, g, b) = (
+            self.r as f32 / 255.,
+            self.g as f32 / 255.,
+            self.b as f32 / 255.,
+        );
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+
+        let h = if delta == 0. {
+            0.
+        } else if max == r {
+            60. * ((g - b) / delta)
+        } else if max == g {
+            60. * ((b - r) / delta + 2.)
+        } else {
+            60. * ((r - g) / delta + 4.)
+        };
+        let h = if h < 0. { h + 360. } else { h };
+        let s = if max == 0. { 0. } else { delta / max };
+        (h, s, max)
+    }
+
+    /// Returns the `Color` at `t` between `self` and `other`, `t` being clamped between 0 and 1
+    ///
+    /// Every channel, alpha included, is interpolated linearly and rounded to the nearest integer.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Color;
+    /// assert_eq!(Color::black().lerp(&Color::white(), 0.5), Color::from(128, 128, 128));
+    /// assert_eq!(Color::red().lerp(&Color::blue(), 2.), Color::blue());
+    /// ```
+    pub fn lerp(&self, other: &Color, t: f32) -> Color {
+        self.interpolate(*other, t.clamp(0., 1.))
+    }
+
+    /// Allows you to create a `Color` from a hex string such as `#FFAA00`
+    ///
+    /// The leading `#` is optional and the digits are case insensitive. The alpha channel is set
+    /// to 255.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Color, ColorParseError};
+    /// assert_eq!(Color::from_hex("#FFAA00"), Ok(Color::from(255, 170, 0)));
+    /// assert_eq!(Color::from_hex("ffaa00"), Ok(Color::from(255, 170, 0)));
+    /// assert_eq!(Color::from_hex("#FA0"), Err(ColorParseError::InvalidLength(3)));
+    /// assert_eq!(Color::from_hex("FFAA0G"), Err(ColorParseError::InvalidDigit('G')));
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if let Some(digit) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidDigit(digit));
         }
+        if digits.len() != 6 {
+            return Err(ColorParseError::InvalidLength(digits.len()));
+        }
+        // Every digit being ASCII, the channels cannot fail to parse nor go past 255
+        let channel = |i: usize| i32::from_str_radix(&digits[i..i + 2], 16).unwrap();
+        Ok(Self {
+            r: channel(0),
+            g: channel(2),
+            b: channel(4),
+            a: 255,
+        })
+    }
+
+    /// Returns the `r`, `g` and `b` values of a `Color` as an uppercase `RRGGBB` hex string
+    ///
+    /// The alpha channel is left out, see [`Color::from_hex`].
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Color;
+    /// assert_eq!(Color::from(255, 170, 0).to_hex(), "FFAA00");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
 
-        Self { r, g, b }
+    // The `Color` at `progress` between `self` and `other`, `progress` going from 0 to 1
+    pub(crate) fn interpolate(self, other: Color, progress: f32) -> Color {
+        let channel =
+            |from: i32, to: i32| math::round(from as f32 + (to - from) as f32 * progress) as i32;
+        Color::from_rgba(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+            channel(self.a, other.a),
+        )
     }
 
     /// Returns the red value of a `Color`
@@ -72,6 +241,17 @@ impl Color {
         self.b
     }
 
+    /// Returns the alpha value of a `Color`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Color;
+    /// assert_eq!(Color::blue().a(), 255);
+    /// ```
+    pub fn a(&self) -> i32 {
+        self.a
+    }
+
     /// Returns a black color
     ///
     /// Example:
@@ -80,7 +260,12 @@ impl Color {
     /// assert_eq!(Color::black(), Color::from(0, 0, 0));
     /// ```
     pub fn black() -> Self {
-        Self { r: 0, g: 0, b: 0 }
+        Self {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255,
+        }
     }
 
     /// Returns a red color
@@ -91,7 +276,12 @@ impl Color {
     /// assert_eq!(Color::red(), Color::from(255, 0, 0));
     /// ```
     pub fn red() -> Self {
-        Self { r: 255, g: 0, b: 0 }
+        Self {
+            r: 255,
+            g: 0,
+            b: 0,
+            a: 255,
+        }
     }
 
     /// Returns a green color
@@ -102,7 +292,12 @@ impl Color {
     /// assert_eq!(Color::green(), Color::from(0, 255, 0));
     /// ```
     pub fn green() -> Self {
-        Self { r: 0, g: 255, b: 0 }
+        Self {
+            r: 0,
+            g: 255,
+            b: 0,
+            a: 255,
+        }
     }
 
     /// Returns a blue color
@@ -113,7 +308,12 @@ impl Color {
     /// assert_eq!(Color::blue(), Color::from(0, 0, 255));
     /// ```
     pub fn blue() -> Self {
-        Self { r: 0, g: 0, b: 255 }
+        Self {
+            r: 0,
+            g: 0,
+            b: 255,
+            a: 255,
+        }
     }
 
     /// Returns a white color
@@ -128,17 +328,173 @@ impl Color {
             r: 255,
             g: 255,
             b: 255,
+            a: 255,
         }
     }
 }
 
+/// Multiplies two `Color`s channel by channel, the usual "multiply" blend mode
+///
+/// Each channel is the product of both channels normalized between 0 and 1, scaled back to 255
+/// and rounded, so that white leaves a `Color` unchanged and black gives black. The alpha
+/// channels are multiplied as well.
+///
+/// Example:
+/// ```
+/// use osb::utils::Color;
+///
+/// let tint = Color::from(255, 128, 0);
+/// assert_eq!(Color::white() * tint, tint);
+/// assert_eq!(Color::from(100, 100, 100) * tint, Color::from(100, 50, 0));
+/// ```
+impl Mul for Color {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let channel = |a: i32, b: i32| math::round(a as f32 * b as f32 / 255.) as i32;
+        Color::from_rgba(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+            channel(self.a, other.a),
+        )
+    }
+}
+
+/// Scales the `r`, `g` and `b` channels of a `Color` by a factor, the alpha channel being kept
+///
+/// Channels are rounded and clamped between 0 and 255, like in [`Color::from`].
+///
+/// Example:
+/// ```
+/// use osb::utils::Color;
+///
+/// assert_eq!(Color::from(100, 50, 0) * 0.5, Color::from(50, 25, 0));
+/// assert_eq!(Color::from(200, 100, 0) * 2., Color::from(255, 200, 0));
+/// ```
+impl Mul<f32> for Color {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        let channel = |value: i32| math::round(value as f32 * factor) as i32;
+        Color::from_rgba(channel(self.r), channel(self.g), channel(self.b), self.a)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::utils::Color;
+    use crate::{utils::Color, ColorParseError};
 
     #[test]
     fn out_of_range() {
         assert_eq!(Color::from(-1, -200, -42), Color::black());
         assert_eq!(Color::from(300, 300, 300), Color::white());
     }
+
+    #[test]
+    fn alpha() {
+        assert_eq!(
+            Color::from_rgba(-1, 300, 42, -5),
+            Color::from_rgba(0, 255, 42, 0)
+        );
+        assert_eq!(Color::from_rgba(255, 255, 255, 255), Color::white());
+        assert_ne!(Color::from_rgba(255, 255, 255, 0), Color::white());
+        assert_eq!(
+            Color::from_rgba(0, 0, 0, 0).interpolate(Color::black(), 0.5),
+            Color::from_rgba(0, 0, 0, 128)
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        let (from, to) = (Color::from(0, 100, 255), Color::from(100, 0, 255));
+        assert_eq!(from.lerp(&to, 0.), from);
+        assert_eq!(from.lerp(&to, 1.), to);
+        assert_eq!(from.lerp(&to, 0.25), Color::from(25, 75, 255));
+        assert_eq!(from.lerp(&to, -1.), from);
+        assert_eq!(from.lerp(&to, f32::INFINITY), to);
+        assert_eq!(
+            Color::from_rgba(0, 0, 0, 0).lerp(&Color::white(), 0.5),
+            Color::from_rgba(128, 128, 128, 128)
+        );
+    }
+
+    #[test]
+    fn mul() {
+        let color = Color::from_rgba(51, 102, 255, 128);
+        assert_eq!(color * Color::white(), color);
+        assert_eq!(color * Color::black(), Color::from_rgba(0, 0, 0, 128));
+        assert_eq!(
+            color * Color::from_rgba(128, 255, 0, 0),
+            Color::from_rgba(26, 102, 0, 0)
+        );
+
+        assert_eq!(color * 1., color);
+        assert_eq!(color * 0.5, Color::from_rgba(26, 51, 128, 128));
+        assert_eq!(color * -1., Color::from_rgba(0, 0, 0, 128));
+        assert_eq!(color * 3., Color::from_rgba(153, 255, 255, 128));
+    }
+
+    #[test]
+    fn hsv() {
+        assert_eq!(Color::from_hsv(0., 0., 0.), Color::black());
+        assert_eq!(Color::from_hsv(42., 0., 1.), Color::white());
+        assert_eq!(Color::from_hsv(240., 1., 1.), Color::blue());
+        assert_eq!(Color::from_hsv(-120., 1., 1.), Color::blue());
+        assert_eq!(Color::from_hsv(600., 1., 1.), Color::blue());
+        assert_eq!(Color::from_hsv(300., 0.5, 0.5), Color::from(128, 64, 128));
+        assert_eq!(Color::from_hsv(0., 2., -1.), Color::black());
+
+        assert_eq!(Color::red().to_hsv(), (0., 1., 1.));
+        assert_eq!(Color::from(255, 0, 255).to_hsv(), (300., 1., 1.));
+        assert_eq!(Color::black().to_hsv(), (0., 0., 0.));
+
+        for color in [
+            Color::from(12, 34, 56),
+            Color::from(200, 100, 50),
+            Color::from(7, 250, 128),
+        ] {
+            let (h, s, v) = color.to_hsv();
+            assert_eq!(Color::from_hsv(h, s, v), color);
+        }
+    }
+
+    #[test]
+    fn from_f32() {
+        assert_eq!(Color::from_f32(1., 0.5, 0.), Color::from(255, 128, 0));
+        assert_eq!(Color::from_f32(0.2, 0.4, 0.6), Color::from(51, 102, 153));
+        assert_eq!(Color::from_f32(-1., 2., f32::NAN), Color::from(0, 255, 0));
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(Color::from_hex("#000000"), Ok(Color::black()));
+        assert_eq!(Color::from_hex("fFfFfF"), Ok(Color::white()));
+        assert_eq!(Color::from_hex(""), Err(ColorParseError::InvalidLength(0)));
+        assert_eq!(
+            Color::from_hex("#FFAA00FF"),
+            Err(ColorParseError::InvalidLength(8))
+        );
+        assert_eq!(
+            Color::from_hex("##FFAA00"),
+            Err(ColorParseError::InvalidDigit('#'))
+        );
+        assert_eq!(
+            Color::from_hex("FFé0"),
+            Err(ColorParseError::InvalidDigit('é'))
+        );
+
+        for color in [Color::from(255, 170, 0), Color::from(1, 2, 254)] {
+            assert_eq!(Color::from_hex(&color.to_hex()), Ok(color));
+        }
+        assert_eq!(Color::from(10, 11, 12).to_hex(), "0A0B0C");
+    }
+
+    #[test]
+    fn hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Color::from(255, 0, 0));
+        assert!(!set.insert(Color::red()));
+        assert!(set.insert(Color::from(0, 0, 0)));
+    }
 }
@@ -1,5 +1,15 @@
 /// Data structure to associate keys of an interval type to a certain value
-#[derive(Debug)]
+///
+/// Intervals are half-open: a value pushed with the range `start..end` is active from `start`
+/// (included) up to `end` (excluded). An empty range `key..key` marks an instant, the value then
+/// only being active at `key` itself.
+///
+/// The `points` are breakpoints sorted by key, each of them holding the values active from its key
+/// up to the next breakpoint. An instant is recorded with two breakpoints sharing the same key: the
+/// first one holds the values active at the key itself, the second one the values active right
+/// after it.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntervalMap<K, V> {
     pub points: Vec<(K, Vec<V>)>,
 }
@@ -10,12 +20,13 @@ impl<K, V> Default for IntervalMap<K, V> {
     }
 }
 
-use std::cmp::{Ord, Ordering::*};
-use std::ops::Range;
+use alloc::vec::Vec;
+use core::cmp::Ord;
+use core::ops::Range;
 
 impl<K, V> IntervalMap<K, V>
 where
-    K: Ord,
+    K: Ord + Clone,
     V: Clone,
 {
     /// Initializes a `IntervalMap`
@@ -23,8 +34,57 @@ where
         Self::default()
     }
 
+    /// Returns the number of breakpoints of our `IntervalMap`
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::utils::IntervalMap;
+    /// let mut interval_map = IntervalMap::new();
+    /// interval_map.push(10..50, 1);
+    /// interval_map.push(30..50, 2);
+    ///
+    /// assert_eq!(interval_map.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if our `IntervalMap` holds no breakpoint
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::utils::IntervalMap;
+    /// let mut interval_map = IntervalMap::new();
+    /// assert!(interval_map.is_empty());
+    ///
+    /// interval_map.push(10..50, 1);
+    /// assert!(!interval_map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Removes every value from our `IntervalMap`
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::utils::IntervalMap;
+    /// let mut interval_map = IntervalMap::new();
+    /// interval_map.push(10..50, 1);
+    ///
+    /// interval_map.clear();
+    /// assert!(interval_map.is_empty());
+    /// assert!(!interval_map.contains(&20));
+    /// ```
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
     /// Adds a value to our `IntervalMap`.
     ///
+    /// The value is active on the half-open interval `range`. An empty range, or a range ending
+    /// before its start, records the value at the instant `range.start` only.
+    ///
     /// In the following example, our value is of integer type, but
     /// it can be anything type that implements the trait `Clone`.
     ///
@@ -35,37 +95,119 @@ where
     /// interval_map.push(10..50, 1);
     /// ```
     pub fn push(&mut self, range: Range<K>, value: V) {
-        let position = match self
+        if range.end <= range.start {
+            self.push_instant(range.start, value);
+            return;
+        }
+
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        for point in &mut self.points[start..end] {
+            point.1.push(value.clone());
+        }
+    }
+
+    // Makes sure a breakpoint exists at `key` and returns the index of the first one
+    fn split_at(&mut self, key: K) -> usize {
+        let index = self.points.partition_point(|(point, _)| *point < key);
+        if !self.is_keyed(index, &key) {
+            let values = self.values_before(index);
+            self.points.insert(index, (key, values));
+        }
+        index
+    }
+
+    fn push_instant(&mut self, key: K, value: V) {
+        let index = self.points.partition_point(|(point, _)| *point < key);
+        if !self.is_keyed(index, &key) {
+            let values = self.values_before(index);
+            self.points.insert(index, (key.clone(), values.clone()));
+            self.points.insert(index, (key, values));
+        } else if !self.is_keyed(index + 1, &key) {
+            let values = self.points[index].1.clone();
+            self.points.insert(index + 1, (key, values));
+        }
+        self.points[index].1.push(value);
+    }
+
+    /// Removes a value pushed to our `IntervalMap` with the same range, returning whether anything
+    /// was removed
+    ///
+    /// One occurrence of `value` is removed from every breakpoint covered by `range`, the
+    /// breakpoints left without effect being dropped so that the `IntervalMap` stays as if `value`
+    /// was never pushed.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::utils::IntervalMap;
+    /// let mut interval_map = IntervalMap::new();
+    /// interval_map.push(10..50, 1);
+    ///
+    /// assert!(interval_map.remove(10..50, &1));
+    /// assert!(!interval_map.contains(&20));
+    /// assert!(interval_map.points.is_empty());
+    /// ```
+    pub fn remove(&mut self, range: Range<K>, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        let start = self
             .points
-            .binary_search_by(|&(ref point, _)| point.cmp(&range.start))
-        {
-            Ok(position) => position,
-            Err(position) => {
-                self.points.insert(
-                    position,
-                    (
-                        range.start,
-                        self.points
-                            .get(position.wrapping_sub(1))
-                            .map(|(_, values)| values.clone())
-                            .unwrap_or_default(),
-                    ),
-                );
-                position
-            }
+            .partition_point(|(point, _)| *point < range.start);
+        let end = if range.end <= range.start {
+            // Instants are only held by the first breakpoint of their key
+            (start + 1).min(self.points.len())
+        } else {
+            self.points.partition_point(|(point, _)| *point < range.end)
         };
-        for (i, point) in self.points.iter_mut().enumerate().skip(position) {
-            match point.0.cmp(&range.end) {
-                Greater => {
-                    let mut new_point = (range.end, point.1.clone());
-                    new_point.1.push(value);
-                    self.points.insert(i, new_point);
-                    return;
-                },
-                _ => point.1.push(value.clone()),
+
+        let mut removed = false;
+        for (_, values) in &mut self.points[start..end] {
+            if let Some(i) = values.iter().position(|other| other == value) {
+                values.remove(i);
+                removed = true;
             }
         }
-        self.points.push((range.end, Vec::new()))
+        if removed {
+            self.collapse();
+        }
+        removed
+    }
+
+    // Drops the breakpoints holding the same values as the breakpoint before them, except for the
+    // first breakpoint of an instant whose values differ right after it
+    fn collapse(&mut self)
+    where
+        V: PartialEq,
+    {
+        let mut i = 0;
+        while i < self.points.len() {
+            let redundant = match i.checked_sub(1) {
+                Some(previous) => self.points[previous].1 == self.points[i].1,
+                None => self.points[i].1.is_empty(),
+            };
+            let instant = self.is_keyed(i + 1, &self.points[i].0);
+            if redundant && !instant {
+                self.points.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // Whether the breakpoint at `index` exists and is keyed by `key`
+    fn is_keyed(&self, index: usize, key: &K) -> bool {
+        self.points
+            .get(index)
+            .is_some_and(|(point, _)| point == key)
+    }
+
+    // Values active right before the breakpoint at `index`
+    fn values_before(&self, index: usize) -> Vec<V> {
+        index
+            .checked_sub(1)
+            .map(|i| self.points[i].1.clone())
+            .unwrap_or_default()
     }
 
     /// Retrieve all of the values that is inside an interval
@@ -86,25 +228,60 @@ where
     /// assert_eq!(result2.next(), Some(&42));
     /// assert_eq!(result2.next(), None);
     /// ```
-    pub fn get(&self, key: &K) -> std::slice::Iter<V> {
-        let index = match self
-            .points
-            .binary_search_by(|&(ref point, _)| point.cmp(key))
-        {
-            Err(index) => {
-                if index == 0 {
-                    return (&[]).iter();
-                } else {
-                    index - 1
-                }
-            }
-            Ok(index) => index,
-        };
+    pub fn get(&self, key: &K) -> core::slice::Iter<'_, V> {
+        let index = self.points.partition_point(|(point, _)| point < key);
+        match self.points.get(index) {
+            Some((point, values)) if point == key => values.iter(),
+            _ => match index.checked_sub(1) {
+                Some(index) => self.points[index].1.iter(),
+                None => [].iter(),
+            },
+        }
+    }
+
+    /// Returns `true` if any value is active at `key`
+    ///
+    /// As intervals are half-open, a value pushed with the range `start..end` is active at `start`
+    /// but not at `end`.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::utils::IntervalMap;
+    /// let mut interval_map = IntervalMap::new();
+    /// interval_map.push(10..50, 1);
+    ///
+    /// assert!(interval_map.contains(&10));
+    /// assert!(interval_map.contains(&49));
+    /// assert!(!interval_map.contains(&50));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.get(key).next().is_some()
+    }
+
+    /// Iterates over the spans between consecutive breakpoints, along with the values active in
+    /// each of them
+    ///
+    /// Spans are half-open like the pushed intervals, and may hold no value when nothing is
+    /// active between two intervals. An instant yields an empty span `key..key` holding the values
+    /// active at `key` itself.
+    ///
+    /// Usage:
+    /// ```
+    /// use osb::utils::IntervalMap;
+    /// let mut interval_map = IntervalMap::new();
+    /// interval_map.push(10..50, 1);
+    /// interval_map.push(30..70, 2);
+    ///
+    /// let mut intervals = interval_map.intervals();
+    /// assert_eq!(intervals.next(), Some((&10..&30, &[1][..])));
+    /// assert_eq!(intervals.next(), Some((&30..&50, &[1, 2][..])));
+    /// assert_eq!(intervals.next(), Some((&50..&70, &[2][..])));
+    /// assert_eq!(intervals.next(), None);
+    /// ```
+    pub fn intervals(&self) -> impl Iterator<Item = (Range<&K>, &[V])> {
         self.points
-            .get(index)
-            .map(|point| &point.1[..])
-            .unwrap_or(&[])
-            .iter()
+            .windows(2)
+            .map(|pair| (&pair[0].0..&pair[1].0, pair[0].1.as_slice()))
     }
 }
 
@@ -135,4 +312,100 @@ mod tests {
         assert_eq!(result.next(), Some(&2));
         assert_eq!(result.next(), None);
     }
+
+    #[test]
+    fn half_open() {
+        let mut interval_map = IntervalMap::new();
+        interval_map.push(10..50, 1);
+
+        assert!(!interval_map.contains(&9));
+        assert!(interval_map.contains(&10));
+        assert!(interval_map.contains(&49));
+        assert!(!interval_map.contains(&50));
+    }
+
+    #[test]
+    fn adjacent() {
+        let mut interval_map = IntervalMap::new();
+        interval_map.push(30..50, 2);
+        interval_map.push(10..30, 1);
+
+        let mut result = interval_map.get(&20);
+        assert_eq!(result.next(), Some(&1));
+        assert_eq!(result.next(), None);
+
+        let mut result = interval_map.get(&30);
+        assert_eq!(result.next(), Some(&2));
+        assert_eq!(result.next(), None);
+
+        assert!(!interval_map.contains(&50));
+    }
+
+    #[test]
+    fn remove() {
+        let mut interval_map = IntervalMap::new();
+        interval_map.push(10..50, 1);
+        interval_map.push(30..70, 2);
+
+        assert!(interval_map.remove(30..70, &2));
+        let mut result = interval_map.get(&40);
+        assert_eq!(result.next(), Some(&1));
+        assert_eq!(result.next(), None);
+        assert!(!interval_map.contains(&60));
+        assert_eq!(interval_map.points, [(10, vec![1]), (50, vec![])]);
+
+        assert!(!interval_map.remove(30..70, &2));
+        assert!(!interval_map.remove(10..50, &3));
+
+        interval_map.push(20..20, 3);
+        interval_map.push(10..50, 1);
+        assert!(interval_map.remove(20..20, &3));
+        assert!(interval_map.remove(10..50, &1));
+        assert_eq!(interval_map.points, [(10, vec![1]), (50, vec![])]);
+        assert!(interval_map.remove(10..50, &1));
+        assert!(interval_map.points.is_empty());
+    }
+
+    #[test]
+    fn intervals() {
+        let mut interval_map = IntervalMap::new();
+        assert_eq!(interval_map.intervals().next(), None);
+
+        interval_map.push(10..20, 1);
+        interval_map.push(30..40, 2);
+        interval_map.push(35..35, 3);
+
+        let intervals: Vec<_> = interval_map.intervals().collect();
+        assert_eq!(
+            intervals,
+            [
+                (&10..&20, &[1][..]),
+                (&20..&30, &[][..]),
+                (&30..&35, &[2][..]),
+                (&35..&35, &[2, 3][..]),
+                (&35..&40, &[2][..]),
+            ]
+        );
+    }
+
+    #[test]
+    fn instant() {
+        let mut interval_map = IntervalMap::new();
+        interval_map.push(10..50, 1);
+        interval_map.push(20..20, 2);
+        interval_map.push(60..60, 3);
+
+        let mut result = interval_map.get(&20);
+        assert_eq!(result.next(), Some(&1));
+        assert_eq!(result.next(), Some(&2));
+        assert_eq!(result.next(), None);
+
+        let mut result = interval_map.get(&21);
+        assert_eq!(result.next(), Some(&1));
+        assert_eq!(result.next(), None);
+
+        assert!(interval_map.contains(&60));
+        assert!(!interval_map.contains(&59));
+        assert!(!interval_map.contains(&61));
+    }
 }
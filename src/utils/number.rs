@@ -1,12 +1,106 @@
-use std::fmt;
-use std::ops::{Add, Neg, Sub};
+use crate::math;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// The number of decimal places floats are written with by default, which is as precise as osu!
+/// gets in practice
+pub const DEFAULT_PRECISION: usize = 3;
+
+/// A number being either an integer or a float
+///
+/// `Number` implements `Eq` and `Hash` so that it can be used as a key. Floats are compared and
+/// hashed through their bit pattern, `0.` and `-0.` being equal just like every `NaN`, so that a
+/// `Number::Float(f32::NAN)` key can be found again.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Number {
     Int(i32),
     Float(f32),
 }
 
+// The bit pattern a float is compared and hashed through, `-0.` being turned into `0.` and every
+// `NaN` into the same one
+fn float_bits(val: f32) -> u32 {
+    if val == 0. {
+        0_f32.to_bits()
+    } else if val.is_nan() {
+        f32::NAN.to_bits()
+    } else {
+        val.to_bits()
+    }
+}
+
+/// Compares two `Number`s exactly, an `Int` never being equal to a `Float`
+///
+/// Example:
+/// ```
+/// use osb::utils::Number;
+///
+/// assert_eq!(Number::Float(0.), Number::Float(-0.));
+/// assert_eq!(Number::Float(f32::NAN), Number::Float(f32::NAN));
+/// assert_ne!(Number::Int(1), Number::Float(1.));
+/// ```
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Number::Int(i), Number::Int(j)) => i == j,
+            (Number::Float(i), Number::Float(j)) => float_bits(*i) == float_bits(*j),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Number {}
+
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match *self {
+            Number::Int(val) => {
+                0_u8.hash(state);
+                val.hash(state);
+            }
+            Number::Float(val) => {
+                1_u8.hash(state);
+                float_bits(val).hash(state);
+            }
+        }
+    }
+}
+
+/// Compares two `Number`s by value
+///
+/// `Number`s are compared exactly, an `Int` and a `Float` being both widened to an `f64` which
+/// holds either without losing any precision. As an `Int` never equals a `Float`, an `Int` comes
+/// before a `Float` of the same value so that the ordering agrees with `PartialEq`. `NaN` can't be compared to anything but itself.
+///
+/// Example:
+/// ```
+/// use osb::utils::Number;
+///
+/// assert!(Number::Int(1) < Number::Int(2));
+/// assert!(Number::Float(1.5) < Number::Int(2));
+/// assert!(Number::Int(1) < Number::Float(1.));
+/// assert_eq!(Number::Float(f32::NAN).partial_cmp(&Number::Int(0)), None);
+/// ```
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(i), Number::Int(j)) => Some(i.cmp(j)),
+            // Agreeing with `PartialEq`, `NaN` equals itself
+            (Number::Float(_), Number::Float(_)) if self == other => Some(Ordering::Equal),
+            (Number::Float(i), Number::Float(j)) => i.partial_cmp(j),
+            (Number::Int(i), Number::Float(j)) => f64::from(*i)
+                .partial_cmp(&f64::from(*j))
+                .map(|ordering| ordering.then(Ordering::Less)),
+            (Number::Float(i), Number::Int(j)) => f64::from(*i)
+                .partial_cmp(&f64::from(*j))
+                .map(|ordering| ordering.then(Ordering::Greater)),
+        }
+    }
+}
+
 impl Number {
     /// Returns the f32 value of a `Number` regardless of if it's an int of a float
     ///
@@ -25,6 +119,140 @@ impl Number {
             Number::Float(val) => val,
         }
     }
+
+    // The `Number` at `progress` between `self` and `other`, `progress` going from 0 to 1
+    pub(crate) fn lerp(self, other: Number, progress: f32) -> Number {
+        let (from, to) = (self.as_f32(), other.as_f32());
+        Number::Float(from + (to - from) * progress).normalized()
+    }
+
+    /// Divides a `Number` by another one, returning `None` if `other` is zero
+    ///
+    /// The quotient of two `Int`s is an `Int` only when the division is exact, a `Float` otherwise,
+    /// so that no precision is silently lost.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Number;
+    ///
+    /// assert_eq!(Number::Int(6).checked_div(Number::Int(2)), Some(Number::Int(3)));
+    /// assert_eq!(Number::Int(5).checked_div(Number::Int(2)), Some(Number::Float(2.5)));
+    /// assert_eq!(Number::Int(1).checked_div(Number::Int(0)), None);
+    /// ```
+    pub fn checked_div(self, other: Number) -> Option<Number> {
+        if other.as_f32() == 0. {
+            return None;
+        }
+        match (self, other) {
+            (Number::Int(i), Number::Int(j)) if i.checked_rem(j) == Some(0) => {
+                Some(Number::Int(i / j))
+            }
+            _ => Some(Number::Float(self.as_f32() / other.as_f32())),
+        }
+    }
+
+    /// Returns an `Int` if the `Number` is a whole-valued float, the `Number` itself otherwise
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Number;
+    ///
+    /// assert_eq!(Number::Float(320.).normalized(), Number::Int(320));
+    /// assert_eq!(Number::Float(0.5).normalized(), Number::Float(0.5));
+    /// ```
+    pub fn normalized(self) -> Number {
+        match self {
+            Number::Float(val)
+                if math::trunc(val) == val && val >= i32::MIN as f32 && val <= i32::MAX as f32 =>
+            {
+                Number::Int(val as i32)
+            }
+            _ => self,
+        }
+    }
+
+    /// Returns the `Number` rounded to `decimals` decimal places, whole-valued results being
+    /// returned as an `Int`
+    ///
+    /// A `Float` too large for `decimals` to make a difference is returned as is.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Number;
+    ///
+    /// assert_eq!(Number::Float(0.123456).rounded(2), Number::Float(0.12));
+    /// assert_eq!(Number::Float(1.996).rounded(2), Number::Int(2));
+    /// assert_eq!(Number::Int(42).rounded(0), Number::Int(42));
+    /// ```
+    pub fn rounded(self, decimals: usize) -> Number {
+        match self {
+            Number::Int(_) => self,
+            Number::Float(val) => {
+                let factor = math::powf(10., decimals as f32);
+                let scaled = val * factor;
+                // Past 2^23, every `f32` is already whole
+                if scaled.is_nan() || scaled.abs() >= 8_388_608. {
+                    return self;
+                }
+                Number::Float(math::round(scaled) / factor).normalized()
+            }
+        }
+    }
+
+    /// Returns the `Number` rounded to the nearest integer as an `Int`, half-way cases being
+    /// rounded away from zero
+    ///
+    /// `Int`s are returned unchanged, `Float`s out of the range of `i32` are saturated.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Number;
+    ///
+    /// assert_eq!(Number::Float(2.6).round(), Number::Int(3));
+    /// assert_eq!(Number::Float(-2.5).round(), Number::Int(-3));
+    /// assert_eq!(Number::Int(5).round(), Number::Int(5));
+    /// ```
+    pub fn round(&self) -> Number {
+        self.whole_with(math::round)
+    }
+
+    /// Returns the largest integer less than or equal to the `Number`, as an `Int`
+    ///
+    /// `Int`s are returned unchanged, `Float`s out of the range of `i32` are saturated.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Number;
+    ///
+    /// assert_eq!(Number::Float(2.6).floor(), Number::Int(2));
+    /// assert_eq!(Number::Float(-2.4).floor(), Number::Int(-3));
+    /// ```
+    pub fn floor(&self) -> Number {
+        self.whole_with(math::floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to the `Number`, as an `Int`
+    ///
+    /// `Int`s are returned unchanged, `Float`s out of the range of `i32` are saturated.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::utils::Number;
+    ///
+    /// assert_eq!(Number::Float(2.4).ceil(), Number::Int(3));
+    /// assert_eq!(Number::Float(-2.6).ceil(), Number::Int(-2));
+    /// ```
+    pub fn ceil(&self) -> Number {
+        self.whole_with(math::ceil)
+    }
+
+    // The `Int` given by `f` applied to a `Float`, an `Int` being left untouched
+    fn whole_with(self, f: fn(f32) -> f32) -> Number {
+        match self {
+            Number::Int(_) => self,
+            Number::Float(val) => Number::Int(f(val) as i32),
+        }
+    }
 }
 
 impl Into<Number> for i32 {
@@ -39,9 +267,23 @@ impl Into<Number> for f32 {
     }
 }
 
+/// Writes the `Number` as it appears in .osb files
+///
+/// Floats are rounded to the precision of the formatter, [`DEFAULT_PRECISION`] decimal places if
+/// none is given, without any trailing zero.
+///
+/// Example:
+/// ```
+/// use osb::utils::Number;
+///
+/// assert_eq!(format!("{}", Number::Float(0.1 + 0.2)), "0.3");
+/// assert_eq!(format!("{}", Number::Float(1.0)), "1");
+/// assert_eq!(format!("{:.1}", Number::Float(0.25)), "0.3");
+/// assert_eq!(format!("{:.5}", Number::Float(0.123456)), "0.12346");
+/// ```
 impl fmt::Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
+        match self.rounded(f.precision().unwrap_or(DEFAULT_PRECISION)) {
             Number::Int(val) => write!(f, "{}", val),
             Number::Float(val) => write!(f, "{}", val),
         }
@@ -74,6 +316,44 @@ impl Sub for Number {
     }
 }
 
+impl Mul for Number {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        match (self, other) {
+            (Number::Int(i), Number::Int(j)) => Number::Int(i * j),
+            (Number::Float(i), Number::Int(j)) => Number::Float(i * j as f32),
+            (Number::Int(i), Number::Float(j)) => Number::Float(i as f32 * j),
+            (Number::Float(i), Number::Float(j)) => Number::Float(i * j),
+        }
+    }
+}
+
+/// Divides a `Number` by another one
+///
+/// The quotient of two `Int`s is an `Int` only when the division is exact, a `Float` otherwise,
+/// like in [`Number::checked_div`]. Dividing by zero never panics and follows the float semantics
+/// instead, giving an infinite or `NaN` `Float`.
+///
+/// Example:
+/// ```
+/// use osb::utils::Number;
+///
+/// assert_eq!(Number::Int(6) / Number::Int(2), Number::Int(3));
+/// assert_eq!(Number::Int(5) / Number::Int(2), Number::Float(2.5));
+/// assert_eq!(Number::Int(1) / Number::Int(0), Number::Float(f32::INFINITY));
+/// ```
+impl Div for Number {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        match (self, other) {
+            (Number::Int(i), Number::Int(j)) if i.checked_rem(j) == Some(0) => Number::Int(i / j),
+            _ => Number::Float(self.as_f32() / other.as_f32()),
+        }
+    }
+}
+
 impl Neg for Number {
     type Output = Self;
 
@@ -89,6 +369,47 @@ impl Neg for Number {
 mod tests {
     use crate::utils::Number;
 
+    #[test]
+    fn round_floor_ceil() {
+        assert_eq!(Number::Float(2.6).round(), Number::Int(3));
+        assert_eq!(Number::Int(5).round(), Number::Int(5));
+        assert_eq!(Number::Float(2.5).round(), Number::Int(3));
+        assert_eq!(Number::Float(-0.4).round(), Number::Int(0));
+
+        assert_eq!(Number::Float(2.6).floor(), Number::Int(2));
+        assert_eq!(Number::Float(-0.5).floor(), Number::Int(-1));
+        assert_eq!(Number::Int(-5).floor(), Number::Int(-5));
+
+        assert_eq!(Number::Float(2.1).ceil(), Number::Int(3));
+        assert_eq!(Number::Float(-0.5).ceil(), Number::Int(0));
+        assert_eq!(Number::Int(7).ceil(), Number::Int(7));
+
+        assert_eq!(Number::Float(1e10).round(), Number::Int(i32::MAX));
+    }
+
+    #[test]
+    fn partial_ord() {
+        assert!(Number::Int(16_777_217) > Number::Int(16_777_216));
+        assert!(Number::Int(16_777_217) > Number::Float(16_777_216.));
+        assert!(Number::Float(16_777_216.) < Number::Int(16_777_217));
+        assert!(Number::Int(i32::MAX) < Number::Float(2_147_483_648.));
+        assert!(Number::Float(-0.5) < Number::Int(0));
+        assert!(Number::Int(3) > Number::Float(2.9));
+        assert!(Number::Float(1.) > Number::Int(1));
+        assert!(Number::Float(0.) >= Number::Float(-0.));
+        assert_eq!(
+            Number::Float(f32::NAN).partial_cmp(&Number::Float(0.)),
+            None
+        );
+
+        let mut numbers = [Number::Float(2.5), Number::Int(-1), Number::Int(2)];
+        numbers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            numbers,
+            [Number::Int(-1), Number::Int(2), Number::Float(2.5)]
+        );
+    }
+
     #[test]
     fn add() {
         let i1 = Number::Int(1);
@@ -115,6 +436,38 @@ mod tests {
         assert_eq!(f2 - f1, Number::Float(1.));
     }
 
+    #[test]
+    fn mul() {
+        let i2 = Number::Int(2);
+        let i3 = Number::Int(3);
+        let f1 = Number::Float(0.5);
+        let f2 = Number::Float(1.5);
+
+        assert_eq!(i2 * i3, Number::Int(6));
+        assert_eq!(f1 * i3, Number::Float(1.5));
+        assert_eq!(i2 * f2, Number::Float(3.));
+        assert_eq!(f1 * f2, Number::Float(0.75));
+    }
+
+    #[test]
+    fn div() {
+        let i2 = Number::Int(2);
+        let i6 = Number::Int(6);
+        let f1 = Number::Float(0.5);
+
+        assert_eq!(i6 / i2, Number::Int(3));
+        assert_eq!(i2 / i6, Number::Float(2. / 6.));
+        assert_eq!(Number::Int(-7) / i2, Number::Float(-3.5));
+        assert_eq!(i6 / f1, Number::Float(12.));
+        assert_eq!(f1 / i2, Number::Float(0.25));
+        assert_eq!(
+            Number::Int(i32::MIN) / Number::Int(-1),
+            Number::Float(2_147_483_648.)
+        );
+        assert_eq!(i2 / Number::Int(0), Number::Float(f32::INFINITY));
+        assert!((Number::Int(0) / Number::Int(0)).as_f32().is_nan());
+    }
+
     #[test]
     fn neg() {
         let i1 = Number::Int(1);
@@ -123,4 +476,54 @@ mod tests {
         assert_eq!(-i1, Number::Int(-1));
         assert_eq!(-f1, Number::Float(-0.5));
     }
+
+    #[test]
+    fn checked_div() {
+        assert_eq!(
+            Number::Int(6).checked_div(Number::Int(2)),
+            Some(Number::Int(3))
+        );
+        assert_eq!(
+            Number::Int(5).checked_div(Number::Int(2)),
+            Some(Number::Float(2.5))
+        );
+        assert_eq!(
+            Number::Float(1.5).checked_div(Number::Int(3)),
+            Some(Number::Float(0.5))
+        );
+        assert_eq!(Number::Int(1).checked_div(Number::Int(0)), None);
+        assert_eq!(Number::Int(1).checked_div(Number::Float(0.)), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Number::Int(42)), "42");
+        assert_eq!(format!("{:.1}", Number::Int(42)), "42");
+        assert_eq!(format!("{}", Number::Float(1. / 3.)), "0.333");
+        assert_eq!(format!("{}", Number::Float(2.0)), "2");
+        assert_eq!(format!("{}", Number::Float(1.9996)), "2");
+        assert_eq!(format!("{}", Number::Float(-0.0004)), "0");
+        assert_eq!(format!("{}", Number::Float(320.125)), "320.125");
+        assert_eq!(format!("{:.0}", Number::Float(320.5)), "321");
+        assert_eq!(format!("{:.5}", Number::Float(0.123456)), "0.12346");
+    }
+
+    #[test]
+    fn hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Number::Int(1));
+        set.insert(Number::Float(1.));
+        set.insert(Number::Float(0.));
+        assert!(!set.insert(Number::Float(-0.)));
+        assert!(!set.insert(Number::Int(1)));
+        assert_eq!(set.len(), 3);
+
+        // `Eq` is reflexive, `NaN` included
+        let nan = Number::Float(f32::NAN);
+        assert_eq!(nan, nan);
+        assert_eq!(nan, Number::Float(-f32::NAN));
+        assert_eq!(nan.partial_cmp(&nan), Some(core::cmp::Ordering::Equal));
+        assert!(set.insert(nan));
+        assert!(set.contains(&Number::Float(-f32::NAN)));
+    }
 }
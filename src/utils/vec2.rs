@@ -1,7 +1,9 @@
+use crate::math;
 use crate::utils::Number;
-use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2 {
     pub x: Number,
     pub y: Number,
@@ -29,6 +31,52 @@ impl Vec2 {
         (x, y).into()
     }
 
+    /// Returns the `Vec2` with both of its coordinates multiplied by `factor`
+    pub fn scale<T>(&self, factor: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        let factor = factor.into();
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+
+    /// Returns the dot product of two `Vec2`s
+    pub fn dot(&self, other: Vec2) -> Number {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the euclidean length of the `Vec2`
+    pub fn length(&self) -> f32 {
+        let (x, y) = (self.x.as_f32(), self.y.as_f32());
+        math::sqrt(x * x + y * y)
+    }
+
+    // The `Vec2` at `progress` between `self` and `other`, `progress` going from 0 to 1
+    pub(crate) fn lerp(self, other: Vec2, progress: f32) -> Self {
+        Self {
+            x: self.x.lerp(other.x, progress),
+            y: self.y.lerp(other.y, progress),
+        }
+    }
+
+    // Converts whole-valued float coordinates to integers
+    pub(crate) fn normalized(self) -> Self {
+        Self {
+            x: self.x.normalized(),
+            y: self.y.normalized(),
+        }
+    }
+
+    // Rounds both coordinates to `decimals` decimal places
+    pub(crate) fn rounded(self, decimals: usize) -> Self {
+        Self {
+            x: self.x.rounded(decimals),
+            y: self.y.rounded(decimals),
+        }
+    }
 }
 
 
@@ -93,7 +141,7 @@ impl Neg for Vec2 {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::Vec2;
+    use crate::utils::{Number, Vec2};
 
     #[test]
     fn add() {
@@ -128,4 +176,37 @@ mod tests {
         let v = Vec2::from(10, 20);
         assert_eq!(-v, Vec2::from(-10, -20));
     }
+
+    #[test]
+    fn scale() {
+        let v = Vec2::from(10, 20);
+        assert_eq!(v.scale(2), Vec2::from(20, 40));
+        assert_eq!(v.scale(0.5), Vec2::from(5., 10.));
+        assert_eq!(Vec2::from(1.5, 2).scale(2), Vec2::from(3., 4));
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(Vec2::from(1, 2).dot(Vec2::from(3, 4)), Number::Int(11));
+        assert_eq!(
+            Vec2::from(0.5, 2).dot(Vec2::from(4, 1.5)),
+            Number::Float(5.)
+        );
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(Vec2::from(3, 4).length(), 5.);
+        assert_eq!(Vec2::from(-3, 4).length(), 5.);
+        assert_eq!(Vec2::from(1.5, 2).length(), 2.5);
+        assert_eq!(Vec2::new().length(), 0.);
+    }
+
+    #[test]
+    fn hash() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Vec2::from(320, 240));
+        assert!(!set.insert(Vec2::from(320, 240)));
+        assert!(set.insert(Vec2::from(320.5, 240)));
+    }
 }
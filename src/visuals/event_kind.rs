@@ -0,0 +1,29 @@
+/// The kinds of events a [`Sprite`](crate::Sprite) holds
+///
+/// See [`Sprite::clear`](crate::Sprite::clear).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventKind {
+    /// [`Move`](crate::event::Move) events
+    Move,
+    /// [`MoveX`](crate::event::MoveX) events
+    MoveX,
+    /// [`MoveY`](crate::event::MoveY) events
+    MoveY,
+    /// [`Fade`](crate::event::Fade) events
+    Fade,
+    /// [`Rotate`](crate::event::Rotate) events
+    Rotate,
+    /// [`Scale`](crate::event::Scale) events
+    Scale,
+    /// [`ScaleVec`](crate::event::ScaleVec) events
+    ScaleVec,
+    /// [`Color`](crate::event::Color) events
+    Color,
+    /// [`HFlip`](crate::event::HFlip) events
+    HFlip,
+    /// [`VFlip`](crate::event::VFlip) events
+    VFlip,
+    /// [`Additive`](crate::event::Additive) events
+    Additive,
+}
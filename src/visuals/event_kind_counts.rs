@@ -0,0 +1,79 @@
+use core::ops::Add;
+
+/// The number of events of each kind held by [`Sprite`](crate::Sprite)s
+///
+/// See [`Module::event_kind_counts`](crate::Module::event_kind_counts).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EventKindCounts {
+    /// Number of [`Move`](crate::event::Move) events
+    pub move_: usize,
+    /// Number of [`MoveX`](crate::event::MoveX) events
+    pub movex: usize,
+    /// Number of [`MoveY`](crate::event::MoveY) events
+    pub movey: usize,
+    /// Number of [`Fade`](crate::event::Fade) events
+    pub fade: usize,
+    /// Number of [`Rotate`](crate::event::Rotate) events
+    pub rotate: usize,
+    /// Number of [`Scale`](crate::event::Scale) events
+    pub scale: usize,
+    /// Number of [`ScaleVec`](crate::event::ScaleVec) events
+    pub scalevec: usize,
+    /// Number of [`Color`](crate::event::Color) events
+    pub color: usize,
+    /// Number of [`HFlip`](crate::event::HFlip) events
+    pub hflip: usize,
+    /// Number of [`VFlip`](crate::event::VFlip) events
+    pub vflip: usize,
+    /// Number of [`Additive`](crate::event::Additive) events
+    pub additive: usize,
+}
+
+impl EventKindCounts {
+    /// Returns the number of events of every kind
+    ///
+    /// Example:
+    /// ```
+    /// use osb::EventKindCounts;
+    ///
+    /// let counts = EventKindCounts {
+    ///     move_: 2,
+    ///     fade: 1,
+    ///     ..EventKindCounts::default()
+    /// };
+    /// assert_eq!(counts.total(), 3);
+    /// ```
+    pub fn total(&self) -> usize {
+        self.move_
+            + self.movex
+            + self.movey
+            + self.fade
+            + self.rotate
+            + self.scale
+            + self.scalevec
+            + self.color
+            + self.hflip
+            + self.vflip
+            + self.additive
+    }
+}
+
+impl Add for EventKindCounts {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            move_: self.move_ + other.move_,
+            movex: self.movex + other.movex,
+            movey: self.movey + other.movey,
+            fade: self.fade + other.fade,
+            rotate: self.rotate + other.rotate,
+            scale: self.scale + other.scale,
+            scalevec: self.scalevec + other.scalevec,
+            color: self.color + other.color,
+            hflip: self.hflip + other.hflip,
+            vflip: self.vflip + other.vflip,
+            additive: self.additive + other.additive,
+        }
+    }
+}
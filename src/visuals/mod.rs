@@ -1,3 +1,9 @@
+mod event_kind;
+mod event_kind_counts;
 mod sprite;
+mod transform;
 
+pub use event_kind::*;
+pub use event_kind_counts::*;
 pub use sprite::*;
+pub use transform::*;
@@ -1,9 +1,28 @@
 use crate::event::*;
-use crate::utils::{IntervalMap, Number, Vec2};
+use crate::lint::{self, Lint, SpriteWarning};
+use crate::utils::{self, IntervalMap, Number, Vec2, DEFAULT_PRECISION};
+use crate::visuals::{EventKind, EventKindCounts, Transform};
+use crate::Easing;
 use crate::Layer;
 use crate::Origin;
+use crate::SpriteMismatch;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
 
-struct EventCollection {
+/// The events of a [`Sprite`], stored by kind
+///
+/// See [`Sprite::take_events`] and [`Sprite::set_events`] to move events between `Sprite`s.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventCollection {
     move_: IntervalMap<i32, Move>,
     movex_: IntervalMap<i32, MoveX>,
     movey_: IntervalMap<i32, MoveY>,
@@ -18,6 +37,8 @@ struct EventCollection {
 }
 
 /// `LoopType`s as defined in the [official osu! specifications](https://osu.ppy.sh/wiki/en/Storyboard_Scripting/Objects)
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LoopType {
     /// Animation will stop on the last frame and continue displaying that last frame
     LoopOnce,
@@ -25,225 +46,1469 @@ pub enum LoopType {
     LoopForever,
 }
 
-fn events_to_str<T>(events: &IntervalMap<i32, T>) -> String
+/// Trigger types as defined in the [official osu! specifications](https://osu.ppy.sh/wiki/en/Storyboard_Scripting/Compound_Commands)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerType {
+    /// Fires on any hitsound
+    HitSound,
+    /// Fires on hitsounds of the `Normal` sample set
+    HitSoundNormal,
+    /// Fires on hitsounds of the `Soft` sample set
+    HitSoundSoft,
+    /// Fires on hitsounds of the `Drum` sample set
+    HitSoundDrum,
+    /// Fires on hitsounds with a whistle
+    HitSoundWhistle,
+    /// Fires on hitsounds with a finish
+    HitSoundFinish,
+    /// Fires on hitsounds with a clap
+    HitSoundClap,
+    /// Fires when the player goes from failing to passing
+    Passing,
+    /// Fires when the player goes from passing to failing
+    Failing,
+}
+
+impl fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TriggerType::HitSound => "HitSound",
+                TriggerType::HitSoundNormal => "HitSoundNormal",
+                TriggerType::HitSoundSoft => "HitSoundSoft",
+                TriggerType::HitSoundDrum => "HitSoundDrum",
+                TriggerType::HitSoundWhistle => "HitSoundWhistle",
+                TriggerType::HitSoundFinish => "HitSoundFinish",
+                TriggerType::HitSoundClap => "HitSoundClap",
+                TriggerType::Passing => "Passing",
+                TriggerType::Failing => "Failing",
+            }
+        )
+    }
+}
+
+// Iterates over every event pushed to an `IntervalMap` exactly once, ordered by start time
+//
+// An event is stored in every point its interval covers, so it is only yielded by the first point
+// keyed by its start time
+fn unique_events<T>(events: &IntervalMap<i32, T>) -> impl Iterator<Item = &T>
 where
     T: Event,
 {
-    let hs: std::collections::HashSet<_> = events
+    events
         .points
         .iter()
-        .flat_map(|(_, inner_vec)| inner_vec.iter().map(|t| t.to_line() + "\n"))
-        .collect();
-    hs.into_iter().collect::<Vec<String>>().join("")
+        .enumerate()
+        .flat_map(move |(i, (time, inner_vec))| {
+            let first = i == 0 || events.points[i - 1].0 != *time;
+            inner_vec
+                .iter()
+                .filter(move |event| first && event.get_start_time() == *time)
+        })
 }
 
-impl EventCollection {
-    pub fn new() -> Self {
-        Self {
-            move_: IntervalMap::new(),
-            movex_: IntervalMap::new(),
-            movey_: IntervalMap::new(),
-            fade_: IntervalMap::new(),
-            rotate_: IntervalMap::new(),
-            scale_: IntervalMap::new(),
-            scalevec_: IntervalMap::new(),
-            color_: IntervalMap::new(),
-            hflip_: IntervalMap::new(),
-            vflip_: IntervalMap::new(),
-            additive_: IntervalMap::new(),
+// The lines written verbatim after the commands of a sprite or of one of its blocks
+fn raw_lines_to_str(raw_lines: &[String]) -> String {
+    raw_lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+// Number of bytes `raw_lines_to_str` returns
+fn raw_lines_len(raw_lines: &[String]) -> usize {
+    raw_lines.iter().map(|line| line.len() + 1).sum()
+}
+
+#[cfg(feature = "std")]
+fn write_raw_lines<W: Write>(w: &mut W, raw_lines: &[String]) -> io::Result<()> {
+    for line in raw_lines {
+        writeln!(w, "{}", line)?;
+    }
+    Ok(())
+}
+
+// Rebuilds an `IntervalMap` by mapping every one of its events
+fn map_events<T, F>(events: &IntervalMap<i32, T>, f: F) -> IntervalMap<i32, T>
+where
+    T: Event + Clone,
+    F: Fn(&T) -> T,
+{
+    filter_map_events(events, |event| Some(f(event)))
+}
+
+// Rebuilds an `IntervalMap` by mapping every one of its events, dropping the ones mapped to `None`
+fn filter_map_events<T, F>(events: &IntervalMap<i32, T>, f: F) -> IntervalMap<i32, T>
+where
+    T: Event + Clone,
+    F: Fn(&T) -> Option<T>,
+{
+    let mut new_events = IntervalMap::new();
+    for event in unique_events(events).filter_map(f) {
+        new_events.push(event.get_start_time()..event.get_end_time(), event);
+    }
+    new_events
+}
+
+// The event setting the value at `time`: the latest started one among the events active at `time`,
+// or else the last one to have ended, or else the first one to come
+fn event_at<T>(events: &IntervalMap<i32, T>, time: i32) -> Option<&T>
+where
+    T: Event + Clone,
+{
+    events
+        .get(&time)
+        .max_by_key(|event| event.get_start_time())
+        .or_else(|| {
+            unique_events(events)
+                .filter(|event| event.get_end_time() <= time)
+                .max_by_key(|event| event.get_end_time())
+        })
+        .or_else(|| unique_events(events).next())
+}
+
+// Whether a parameter event of `events` applies at `time`, a dynamic one from its start time until
+// its end time and a static one from its start time on, as osu! keeps static parameters
+fn parameter_at<T>(events: &IntervalMap<i32, T>, time: i32) -> bool
+where
+    T: Event + Clone,
+{
+    events
+        .get(&time)
+        .any(|event| event.get_start_time() < event.get_end_time())
+        || unique_events(events).any(|event| {
+            event.get_start_time() == event.get_end_time() && event.get_start_time() <= time
+        })
+}
+
+// Pushes a copy of every event of `other` to `events`
+fn append_events<T>(events: &mut IntervalMap<i32, T>, other: &IntervalMap<i32, T>)
+where
+    T: Event + Clone,
+{
+    for event in unique_events(other) {
+        events.push(event.get_start_time()..event.get_end_time(), event.clone());
+    }
+}
+
+// Lines of the events of each cycle of length `period` starting from `origin`, every event being
+// moved back to the first cycle so that identical cycles hold identical lines
+fn cycle_lines<T>(events: &IntervalMap<i32, T>, origin: i32, period: i32) -> Vec<Vec<String>>
+where
+    T: Event,
+{
+    let mut cycles: Vec<Vec<String>> = Vec::new();
+    for event in unique_events(events) {
+        let cycle = (event.get_start_time() - origin) / period;
+        let line = event.shifted(-cycle * period).to_line();
+        let cycle = cycle as usize;
+        if cycles.len() <= cycle {
+            cycles.resize(cycle + 1, Vec::new());
         }
+        cycles[cycle].push(line);
     }
+    for lines in &mut cycles {
+        lines.sort();
+    }
+    cycles
+}
 
-    pub fn to_str(&self) -> String {
-        format!(
-            "{}{}{}{}{}{}{}{}{}{}{}",
-            events_to_str(&self.move_),
-            events_to_str(&self.movex_),
-            events_to_str(&self.movey_),
-            events_to_str(&self.fade_),
-            events_to_str(&self.rotate_),
-            events_to_str(&self.scale_),
-            events_to_str(&self.scalevec_),
-            events_to_str(&self.color_),
-            events_to_str(&self.hflip_),
-            events_to_str(&self.vflip_),
-            events_to_str(&self.additive_),
-        )
+// Splits the events between the ones of the first cycle, made relative to `origin` and set at
+// `depth`, and the ones of the cycles from `count` onwards, the cycles in between being dropped
+fn split_cycles<T>(
+    events: &IntervalMap<i32, T>,
+    origin: i32,
+    period: i32,
+    count: i32,
+    depth: usize,
+) -> (IntervalMap<i32, T>, IntervalMap<i32, T>)
+where
+    T: Event + Clone,
+{
+    let (mut first, mut rest) = (IntervalMap::new(), IntervalMap::new());
+    for event in unique_events(events) {
+        let cycle = (event.get_start_time() - origin) / period;
+        if cycle == 0 {
+            let mut event = event.shifted(-origin);
+            event.set_depth(depth);
+            first.push(event.get_start_time()..event.get_end_time(), event);
+        } else if cycle >= count {
+            rest.push(event.get_start_time()..event.get_end_time(), event.clone());
+        }
     }
+    (first, rest)
 }
 
-enum SpriteType {
-    Sprite,
-    Animation {
-        frame_count: u32,
-        frame_delay: u32,
-        loop_type: LoopType,
-    },
+// The reflection of the x-coordinate `x` about the vertical center of the playfield
+fn mirror_x(x: Number) -> Number {
+    (Number::Int(640) - x).normalized()
 }
 
-/// The struct corresponding to sprites
-pub struct Sprite {
-    events: EventCollection,
-    current_depth: usize,
-    path: String,
-    pos: Vec2,
-    layer: Layer,
-    origin: Origin,
-    start_time: Option<i32>,
-    end_time: Option<i32>,
-    type_: SpriteType,
+fn mirror_vec2_x(pos: Vec2) -> Vec2 {
+    Vec2::from(mirror_x(pos.x), pos.y)
 }
 
-// Adding an event to a sprite
-macro_rules! add_event {
-    ($sprite:ident, $event:ident, $events:expr) => {
-        // Adjusting sprite's start and end values
-        let (event_start, event_end) = ($event.get_start_time(), $event.get_end_time());
-        match $sprite.start_time {
-            Some(sprite_start) => {
-                if event_start < sprite_start {
-                    $sprite.start_time = Some(event_start)
+// The earliest start time of an event strictly after `after`
+fn next_start_time<T>(events: &IntervalMap<i32, T>, after: i32) -> Option<i32>
+where
+    T: Event,
+{
+    let index = events.points.partition_point(|(time, _)| *time <= after);
+    events.points[index..]
+        .iter()
+        .find(|(time, values)| values.iter().any(|event| event.get_start_time() == *time))
+        .map(|(time, _)| *time)
+}
+
+// Rebuilds an `IntervalMap` keeping only the first of the events equal to each other
+fn dedup_events<T>(events: &IntervalMap<i32, T>) -> IntervalMap<i32, T>
+where
+    T: Event + Clone + PartialEq,
+{
+    let mut new_events = IntervalMap::new();
+    // Events are yielded by start time, equal events can only be found among the ones starting
+    // at the same time
+    let mut same_start: Vec<&T> = Vec::new();
+    for event in unique_events(events) {
+        if same_start
+            .first()
+            .is_some_and(|first| first.get_start_time() != event.get_start_time())
+        {
+            same_start.clear();
+        }
+        if !same_start.contains(&event) {
+            same_start.push(event);
+            new_events.push(event.get_start_time()..event.get_end_time(), event.clone());
+        }
+    }
+    new_events
+}
+
+// Lines of the events ordered by start time, without their newline, identical lines being yielded
+// once in the order they were first seen, with their values rounded to `precision` decimal places
+// if set, to `DEFAULT_PRECISION` otherwise
+fn event_lines<T>(
+    events: &IntervalMap<i32, T>,
+    precision: Option<usize>,
+) -> impl Iterator<Item = String> + '_
+where
+    T: Event,
+{
+    let mut seen = BTreeSet::new();
+    unique_events(events)
+        .map(move |event| event.to_line_with_precision(precision.unwrap_or(DEFAULT_PRECISION)))
+        .filter(move |line| seen.insert(line.clone()))
+}
+
+// The lines of `event_lines`, each followed by a newline
+fn events_to_str<T>(events: &IntervalMap<i32, T>, precision: Option<usize>) -> String
+where
+    T: Event,
+{
+    event_lines(events, precision)
+        .map(|line| line + "\n")
+        .collect()
+}
+
+// Writes the lines `events_to_str` renders to `w`, one at a time
+#[cfg(feature = "std")]
+fn write_events<T, W>(
+    w: &mut W,
+    events: &IntervalMap<i32, T>,
+    precision: Option<usize>,
+) -> io::Result<()>
+where
+    T: Event,
+    W: Write,
+{
+    for line in event_lines(events, precision) {
+        writeln!(w, "{}", line)?;
+    }
+    Ok(())
+}
+
+// Number of bytes `events_to_str` renders
+fn events_byte_len<T>(events: &IntervalMap<i32, T>, precision: Option<usize>) -> usize
+where
+    T: Event,
+{
+    event_lines(events, precision)
+        .map(|line| line.len() + 1)
+        .sum()
+}
+
+// Value events made of static and dynamic keyframes, see `collapse_statics`
+trait Keyframe: Event + Clone {
+    type Value: Copy;
+
+    // The depth, timestamp and value of a static event
+    fn as_static(&self) -> Option<(usize, i32, Self::Value)>;
+    fn new_static(depth: usize, time: i32, value: Self::Value) -> Self;
+    fn new_linear(
+        depth: usize,
+        start_time: i32,
+        end_time: i32,
+        start_value: Self::Value,
+        end_value: Self::Value,
+    ) -> Self;
+    fn value_at(&self, time: i32) -> Self::Value;
+}
+
+macro_rules! keyframe {
+    ($kind:ident, $value:ty) => {
+        impl Keyframe for $kind {
+            type Value = $value;
+
+            fn as_static(&self) -> Option<(usize, i32, $value)> {
+                match self {
+                    $kind::Static(depth, time, value) => Some((*depth, *time, *value)),
+                    _ => None,
                 }
             }
-            None => $sprite.start_time = Some(event_start),
+
+            fn new_static(depth: usize, time: i32, value: $value) -> Self {
+                $kind::Static(depth, time, value)
+            }
+
+            fn new_linear(
+                depth: usize,
+                start_time: i32,
+                end_time: i32,
+                start_value: $value,
+                end_value: $value,
+            ) -> Self {
+                $kind::Dynamic(
+                    depth,
+                    Easing::Linear,
+                    start_time,
+                    end_time,
+                    start_value,
+                    end_value,
+                )
+            }
+
+            fn value_at(&self, time: i32) -> $value {
+                $kind::value_at(self, time)
+            }
         }
+    };
+}
 
-        match $sprite.end_time {
-            Some(sprite_end) => {
-                if sprite_end < event_end {
-                    $sprite.end_time = Some(event_end)
+keyframe!(Move, Vec2);
+keyframe!(MoveX, Number);
+keyframe!(MoveY, Number);
+keyframe!(Fade, Number);
+keyframe!(Rotate, Number);
+keyframe!(Scale, Number);
+keyframe!(ScaleVec, Vec2);
+keyframe!(Color, utils::Color);
+
+// Rebuilds an `IntervalMap` with its runs of static events lying on the same line merged into
+// linear events, see `collapse_run`
+//
+// A run is broken by any other event, and static events happening while another event of the same
+// kind is in progress are left as they are.
+fn collapse_statics<T>(events: &IntervalMap<i32, T>, decimals: usize) -> IntervalMap<i32, T>
+where
+    T: Keyframe,
+{
+    let mut collapsed = Vec::new();
+    let mut run: Vec<(usize, i32, T::Value)> = Vec::new();
+    let mut busy_until = i32::MIN;
+    for event in unique_events(events) {
+        match event.as_static() {
+            Some(keyframe) if keyframe.1 > busy_until => {
+                let breaks_run = matches!(
+                    run.last(),
+                    Some(last) if last.0 != keyframe.0 || last.1 >= keyframe.1
+                );
+                if breaks_run {
+                    collapse_run(&run, decimals, &mut collapsed);
+                    run.clear();
                 }
+                run.push(keyframe);
+            }
+            _ => {
+                collapse_run(&run, decimals, &mut collapsed);
+                run.clear();
+                busy_until = busy_until.max(event.get_end_time());
+                collapsed.push(event.clone());
             }
-            None => $sprite.end_time = Some(event_end),
         }
+    }
+    collapse_run(&run, decimals, &mut collapsed);
 
-        // Pushing it to the events
-        $event.set_depth($sprite.current_depth);
-        $events.push(event_start..event_end, $event);
+    let mut new_events = IntervalMap::new();
+    for event in collapsed {
+        new_events.push(event.get_start_time()..event.get_end_time(), event);
+    }
+    new_events
+}
+
+// Pushes the static events of `run`, sorted by time, to `collapsed`, each stretch of at least
+// three of them lying on the same line, or of at least two identical ones, being merged into a
+// single linear event
+//
+// Events lie on a line when the values of the linear event, rendered to `decimals` decimal places,
+// are the ones of the static events. Only a few events of a stretch are checked against the line
+// so that long runs of per-frame events stay cheap.
+fn collapse_run<T>(run: &[(usize, i32, T::Value)], decimals: usize, collapsed: &mut Vec<T>)
+where
+    T: Keyframe,
+{
+    let on_line = |event: &T, (depth, time, value): (usize, i32, T::Value)| {
+        T::new_static(depth, time, event.value_at(time)).to_line_with_precision(decimals)
+            == T::new_static(depth, time, value).to_line_with_precision(decimals)
     };
+    let line = |(depth, start_time, start_value): (usize, i32, T::Value),
+                (_, end_time, end_value): (usize, i32, T::Value)| {
+        T::new_linear(depth, start_time, end_time, start_value, end_value)
+    };
+
+    let mut start = 0;
+    while start < run.len() {
+        let mut end = start;
+        while end + 1 < run.len() {
+            let candidate = line(run[start], run[end + 1]);
+            let on_candidate = [start + 1, (start + end) / 2 + 1, end]
+                .iter()
+                .filter(|&&index| start < index && index <= end)
+                .all(|&index| on_line(&candidate, run[index]));
+            if !on_candidate {
+                break;
+            }
+            end += 1;
+        }
+
+        let (depth, time, value) = run[start];
+        let identical = end > start && on_line(&T::new_static(depth, time, value), run[end]);
+        if end - start >= 2 || identical {
+            collapsed.push(line(run[start], run[end]));
+            start = end + 1;
+        } else {
+            collapsed.push(T::new_static(depth, time, value));
+            start += 1;
+        }
+    }
 }
 
-impl Sprite {
-    /// Initializes a new `Sprite` or an animation `Sprite`
-    ///
-    /// See [trait implementations](#trait-implementations) to see how you can create a Sprite element
-    pub fn new<T>(args: T) -> Self
-    where
-        T: Into<Sprite>,
-    {
-        args.into()
+// Start and end times of the events ending before they start
+fn backwards_events<T>(events: &IntervalMap<i32, T>) -> impl Iterator<Item = (i32, i32)> + '_
+where
+    T: Event,
+{
+    unique_events(events)
+        .map(|event| (event.get_start_time(), event.get_end_time()))
+        .filter(|(start_time, end_time)| end_time < start_time)
+}
+
+impl Default for EventCollection {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// Performs the event [`Move`] to a `Sprite`
+impl EventCollection {
+    /// Initializes an empty `EventCollection`
+    pub fn new() -> Self {
+        Self {
+            move_: IntervalMap::new(),
+            movex_: IntervalMap::new(),
+            movey_: IntervalMap::new(),
+            fade_: IntervalMap::new(),
+            rotate_: IntervalMap::new(),
+            scale_: IntervalMap::new(),
+            scalevec_: IntervalMap::new(),
+            color_: IntervalMap::new(),
+            hflip_: IntervalMap::new(),
+            vflip_: IntervalMap::new(),
+            additive_: IntervalMap::new(),
+        }
+    }
+
+    /// Removes every event of the kind `kind`
     ///
+    /// Example:
     /// ```
-    /// use osb::{Sprite, Easing, utils::Vec2};
+    /// use osb::{EventKind, Sprite};
     ///
     /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.fade_((0, 1)).move_((0, 320, 240));
     ///
-    /// // There's a `Vec2` type you can use if you wish
-    /// sprite.move_((Easing::Out, 0, 1000, Vec2::from(0, 0), Vec2::from(320, 240)));
-    /// // But you're not forced to! Giving pairs of integers automatically translates to a `Vec2`
-    /// sprite.move_((Easing::QuadInOut, 1000, 2000, 320, 240, 100, 100));
-    /// // And of course you can use a static move too
-    /// sprite.move_((3000, Vec2::from(320, 240)));
-    /// // Please refer to the trait implementations of the event to see everything you can do
+    /// let mut events = sprite.take_events();
+    /// events.clear(EventKind::Fade);
+    /// sprite.set_events(events);
+    /// assert_eq!(sprite.event_kind_counts().total(), 1);
     /// ```
-    pub fn move_<T>(&mut self, args: T)
-    where
-        T: Into<Move>,
-    {
-        let mut event = args.into();
-        add_event!(self, event, self.events.move_);
+    pub fn clear(&mut self, kind: EventKind) {
+        match kind {
+            EventKind::Move => self.move_.clear(),
+            EventKind::MoveX => self.movex_.clear(),
+            EventKind::MoveY => self.movey_.clear(),
+            EventKind::Fade => self.fade_.clear(),
+            EventKind::Rotate => self.rotate_.clear(),
+            EventKind::Scale => self.scale_.clear(),
+            EventKind::ScaleVec => self.scalevec_.clear(),
+            EventKind::Color => self.color_.clear(),
+            EventKind::HFlip => self.hflip_.clear(),
+            EventKind::VFlip => self.vflip_.clear(),
+            EventKind::Additive => self.additive_.clear(),
+        }
     }
 
-    /// Performs the event [`MoveX`] to a `Sprite`
-    ///
-    /// ```
-    /// use osb::{Sprite, Easing, utils::Vec2};
-    ///
-    /// let mut sprite = Sprite::new("res/sprite.png");
-    /// sprite.movex_((0, 320));
-    /// // Please refer to the trait implementations of the event to see everything you can do
-    /// ```
-    pub fn movex_<T>(&mut self, args: T)
-    where
-        T: Into<MoveX>,
-    {
-        let mut event = args.into();
-        add_event!(self, event, self.events.movex_);
+    pub(crate) fn to_str(&self, precision: Option<usize>) -> String {
+        format!(
+            "{}{}{}{}{}{}{}{}{}{}{}",
+            events_to_str(&self.move_, precision),
+            events_to_str(&self.movex_, precision),
+            events_to_str(&self.movey_, precision),
+            events_to_str(&self.fade_, precision),
+            events_to_str(&self.rotate_, precision),
+            events_to_str(&self.scale_, precision),
+            events_to_str(&self.scalevec_, precision),
+            events_to_str(&self.color_, precision),
+            events_to_str(&self.hflip_, precision),
+            events_to_str(&self.vflip_, precision),
+            events_to_str(&self.additive_, precision),
+        )
     }
 
-    /// Performs the event [`MoveY`] to a `Sprite`
-    ///
-    /// ```
-    /// use osb::{Sprite, Easing, utils::Vec2};
-    ///
-    /// let mut sprite = Sprite::new("res/sprite.png");
-    /// sprite.movey_((0, 240));
-    /// // Please refer to the trait implementations of the event to see everything you can do
-    /// ```
-    pub fn movey_<T>(&mut self, args: T)
-    where
-        T: Into<MoveY>,
-    {
-        let mut event = args.into();
-        add_event!(self, event, self.events.movey_);
+    // Number of lines `to_str` renders
+    pub(crate) fn line_count(&self, precision: Option<usize>) -> usize {
+        event_lines(&self.move_, precision).count()
+            + event_lines(&self.movex_, precision).count()
+            + event_lines(&self.movey_, precision).count()
+            + event_lines(&self.fade_, precision).count()
+            + event_lines(&self.rotate_, precision).count()
+            + event_lines(&self.scale_, precision).count()
+            + event_lines(&self.scalevec_, precision).count()
+            + event_lines(&self.color_, precision).count()
+            + event_lines(&self.hflip_, precision).count()
+            + event_lines(&self.vflip_, precision).count()
+            + event_lines(&self.additive_, precision).count()
     }
 
-    /// Performs the event [`Fade`] to a `Sprite`
-    ///
-    /// ```
-    /// use osb::{Sprite, Easing, utils::Vec2};
-    ///
-    /// let mut sprite = Sprite::new("res/sprite.png");
-    /// sprite.fade_((0, 1));
-    /// // Please refer to the trait implementations of the event to see everything you can do
-    /// ```
-    pub fn fade_<T>(&mut self, args: T)
-    where
-        T: Into<Fade>,
-    {
-        let mut event = args.into();
-        add_event!(self, event, self.events.fade_);
+    #[cfg(feature = "std")]
+    pub(crate) fn write_to<W: Write>(&self, w: &mut W, precision: Option<usize>) -> io::Result<()> {
+        write_events(w, &self.move_, precision)?;
+        write_events(w, &self.movex_, precision)?;
+        write_events(w, &self.movey_, precision)?;
+        write_events(w, &self.fade_, precision)?;
+        write_events(w, &self.rotate_, precision)?;
+        write_events(w, &self.scale_, precision)?;
+        write_events(w, &self.scalevec_, precision)?;
+        write_events(w, &self.color_, precision)?;
+        write_events(w, &self.hflip_, precision)?;
+        write_events(w, &self.vflip_, precision)?;
+        write_events(w, &self.additive_, precision)
     }
 
-    /// Performs the event [`Rotate`] to a `Sprite`
+    // Start and end times of the events ending before they start, sorted
+    pub(crate) fn backwards_events(&self) -> Vec<(i32, i32)> {
+        let mut events = backwards_events(&self.move_)
+            .chain(backwards_events(&self.movex_))
+            .chain(backwards_events(&self.movey_))
+            .chain(backwards_events(&self.fade_))
+            .chain(backwards_events(&self.rotate_))
+            .chain(backwards_events(&self.scale_))
+            .chain(backwards_events(&self.scalevec_))
+            .chain(backwards_events(&self.color_))
+            .chain(backwards_events(&self.hflip_))
+            .chain(backwards_events(&self.vflip_))
+            .chain(backwards_events(&self.additive_))
+            .collect::<Vec<(i32, i32)>>();
+        events.sort_unstable();
+        events
+    }
+
+    // Number of bytes `to_str` renders
+    pub(crate) fn byte_len(&self, precision: Option<usize>) -> usize {
+        events_byte_len(&self.move_, precision)
+            + events_byte_len(&self.movex_, precision)
+            + events_byte_len(&self.movey_, precision)
+            + events_byte_len(&self.fade_, precision)
+            + events_byte_len(&self.rotate_, precision)
+            + events_byte_len(&self.scale_, precision)
+            + events_byte_len(&self.scalevec_, precision)
+            + events_byte_len(&self.color_, precision)
+            + events_byte_len(&self.hflip_, precision)
+            + events_byte_len(&self.vflip_, precision)
+            + events_byte_len(&self.additive_, precision)
+    }
+
+    pub(crate) fn kind_counts(&self) -> EventKindCounts {
+        EventKindCounts {
+            move_: unique_events(&self.move_).count(),
+            movex: unique_events(&self.movex_).count(),
+            movey: unique_events(&self.movey_).count(),
+            fade: unique_events(&self.fade_).count(),
+            rotate: unique_events(&self.rotate_).count(),
+            scale: unique_events(&self.scale_).count(),
+            scalevec: unique_events(&self.scalevec_).count(),
+            color: unique_events(&self.color_).count(),
+            hflip: unique_events(&self.hflip_).count(),
+            vflip: unique_events(&self.vflip_).count(),
+            additive: unique_events(&self.additive_).count(),
+        }
+    }
+
+    pub(crate) fn reversed(&self, total_duration: i32) -> Self {
+        Self {
+            move_: map_events(&self.move_, |e| e.reversed(total_duration)),
+            movex_: map_events(&self.movex_, |e| e.reversed(total_duration)),
+            movey_: map_events(&self.movey_, |e| e.reversed(total_duration)),
+            fade_: map_events(&self.fade_, |e| e.reversed(total_duration)),
+            rotate_: map_events(&self.rotate_, |e| e.reversed(total_duration)),
+            scale_: map_events(&self.scale_, |e| e.reversed(total_duration)),
+            scalevec_: map_events(&self.scalevec_, |e| e.reversed(total_duration)),
+            color_: map_events(&self.color_, |e| e.reversed(total_duration)),
+            hflip_: map_events(&self.hflip_, |e| e.reversed(total_duration)),
+            vflip_: map_events(&self.vflip_, |e| e.reversed(total_duration)),
+            additive_: map_events(&self.additive_, |e| e.reversed(total_duration)),
+        }
+    }
+
+    pub(crate) fn shifted(&self, offset: i32) -> Self {
+        Self {
+            move_: map_events(&self.move_, |e| e.shifted(offset)),
+            movex_: map_events(&self.movex_, |e| e.shifted(offset)),
+            movey_: map_events(&self.movey_, |e| e.shifted(offset)),
+            fade_: map_events(&self.fade_, |e| e.shifted(offset)),
+            rotate_: map_events(&self.rotate_, |e| e.shifted(offset)),
+            scale_: map_events(&self.scale_, |e| e.shifted(offset)),
+            scalevec_: map_events(&self.scalevec_, |e| e.shifted(offset)),
+            color_: map_events(&self.color_, |e| e.shifted(offset)),
+            hflip_: map_events(&self.hflip_, |e| e.shifted(offset)),
+            vflip_: map_events(&self.vflip_, |e| e.shifted(offset)),
+            additive_: map_events(&self.additive_, |e| e.shifted(offset)),
+        }
+    }
+
+    pub(crate) fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        Self {
+            move_: map_events(&self.move_, |e| e.stretched(factor, pivot)),
+            movex_: map_events(&self.movex_, |e| e.stretched(factor, pivot)),
+            movey_: map_events(&self.movey_, |e| e.stretched(factor, pivot)),
+            fade_: map_events(&self.fade_, |e| e.stretched(factor, pivot)),
+            rotate_: map_events(&self.rotate_, |e| e.stretched(factor, pivot)),
+            scale_: map_events(&self.scale_, |e| e.stretched(factor, pivot)),
+            scalevec_: map_events(&self.scalevec_, |e| e.stretched(factor, pivot)),
+            color_: map_events(&self.color_, |e| e.stretched(factor, pivot)),
+            hflip_: map_events(&self.hflip_, |e| e.stretched(factor, pivot)),
+            vflip_: map_events(&self.vflip_, |e| e.stretched(factor, pivot)),
+            additive_: map_events(&self.additive_, |e| e.stretched(factor, pivot)),
+        }
+    }
+
+    pub(crate) fn optimized(&self, decimals: usize) -> Self {
+        Self {
+            move_: collapse_statics(&self.move_, decimals),
+            movex_: collapse_statics(&self.movex_, decimals),
+            movey_: collapse_statics(&self.movey_, decimals),
+            fade_: collapse_statics(&self.fade_, decimals),
+            rotate_: collapse_statics(&self.rotate_, decimals),
+            scale_: collapse_statics(&self.scale_, decimals),
+            scalevec_: collapse_statics(&self.scalevec_, decimals),
+            color_: collapse_statics(&self.color_, decimals),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn deduped(&self) -> Self {
+        Self {
+            move_: dedup_events(&self.move_),
+            movex_: dedup_events(&self.movex_),
+            movey_: dedup_events(&self.movey_),
+            fade_: dedup_events(&self.fade_),
+            rotate_: dedup_events(&self.rotate_),
+            scale_: dedup_events(&self.scale_),
+            scalevec_: dedup_events(&self.scalevec_),
+            color_: dedup_events(&self.color_),
+            hflip_: dedup_events(&self.hflip_),
+            vflip_: dedup_events(&self.vflip_),
+            additive_: dedup_events(&self.additive_),
+        }
+    }
+
+    // Events whose x-coordinates are reflected about the vertical center of the playfield, see
+    // `Sprite::mirrored_x`
+    pub(crate) fn mirrored_x(&self) -> Self {
+        Self {
+            move_: map_events(&self.move_, |e| match e {
+                Move::Static(depth, time, pos) => Move::Static(*depth, *time, mirror_vec2_x(*pos)),
+                Move::Dynamic(depth, easing, start_time, end_time, start_pos, end_pos) => {
+                    Move::Dynamic(
+                        *depth,
+                        *easing,
+                        *start_time,
+                        *end_time,
+                        mirror_vec2_x(*start_pos),
+                        mirror_vec2_x(*end_pos),
+                    )
+                }
+            }),
+            movex_: map_events(&self.movex_, |e| match e {
+                MoveX::Static(depth, time, x) => MoveX::Static(*depth, *time, mirror_x(*x)),
+                MoveX::Dynamic(depth, easing, start_time, end_time, start_x, end_x) => {
+                    MoveX::Dynamic(
+                        *depth,
+                        *easing,
+                        *start_time,
+                        *end_time,
+                        mirror_x(*start_x),
+                        mirror_x(*end_x),
+                    )
+                }
+            }),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn truncated(&self, end: i32) -> Self {
+        Self {
+            move_: filter_map_events(&self.move_, |e| e.truncated(end)),
+            movex_: filter_map_events(&self.movex_, |e| e.truncated(end)),
+            movey_: filter_map_events(&self.movey_, |e| e.truncated(end)),
+            fade_: filter_map_events(&self.fade_, |e| e.truncated(end)),
+            rotate_: filter_map_events(&self.rotate_, |e| e.truncated(end)),
+            scale_: filter_map_events(&self.scale_, |e| e.truncated(end)),
+            scalevec_: filter_map_events(&self.scalevec_, |e| e.truncated(end)),
+            color_: filter_map_events(&self.color_, |e| e.truncated(end)),
+            hflip_: filter_map_events(&self.hflip_, |e| e.truncated(end)),
+            vflip_: filter_map_events(&self.vflip_, |e| e.truncated(end)),
+            additive_: filter_map_events(&self.additive_, |e| e.truncated(end)),
+        }
+    }
+
+    // Earliest start time of an event strictly after `after`
+    pub(crate) fn next_start_time(&self, after: i32) -> Option<i32> {
+        [
+            next_start_time(&self.move_, after),
+            next_start_time(&self.movex_, after),
+            next_start_time(&self.movey_, after),
+            next_start_time(&self.fade_, after),
+            next_start_time(&self.rotate_, after),
+            next_start_time(&self.scale_, after),
+            next_start_time(&self.scalevec_, after),
+            next_start_time(&self.color_, after),
+            next_start_time(&self.hflip_, after),
+            next_start_time(&self.vflip_, after),
+            next_start_time(&self.additive_, after),
+        ]
+        .iter()
+        .flatten()
+        .min()
+        .copied()
+    }
+
+    // Timestamp of the earliest event
+    pub(crate) fn start_time(&self) -> Option<i32> {
+        [
+            self.move_.points.first().map(|(time, _)| *time),
+            self.movex_.points.first().map(|(time, _)| *time),
+            self.movey_.points.first().map(|(time, _)| *time),
+            self.fade_.points.first().map(|(time, _)| *time),
+            self.rotate_.points.first().map(|(time, _)| *time),
+            self.scale_.points.first().map(|(time, _)| *time),
+            self.scalevec_.points.first().map(|(time, _)| *time),
+            self.color_.points.first().map(|(time, _)| *time),
+            self.hflip_.points.first().map(|(time, _)| *time),
+            self.vflip_.points.first().map(|(time, _)| *time),
+            self.additive_.points.first().map(|(time, _)| *time),
+        ]
+        .iter()
+        .flatten()
+        .min()
+        .copied()
+    }
+
+    // Timestamp at which the last event ends
+    pub(crate) fn end_time(&self) -> Option<i32> {
+        [
+            self.move_.points.last().map(|(time, _)| *time),
+            self.movex_.points.last().map(|(time, _)| *time),
+            self.movey_.points.last().map(|(time, _)| *time),
+            self.fade_.points.last().map(|(time, _)| *time),
+            self.rotate_.points.last().map(|(time, _)| *time),
+            self.scale_.points.last().map(|(time, _)| *time),
+            self.scalevec_.points.last().map(|(time, _)| *time),
+            self.color_.points.last().map(|(time, _)| *time),
+            self.hflip_.points.last().map(|(time, _)| *time),
+            self.vflip_.points.last().map(|(time, _)| *time),
+            self.additive_.points.last().map(|(time, _)| *time),
+        ]
+        .iter()
+        .flatten()
+        .max()
+        .copied()
+    }
+
+    // Lines of every cycle of length `period` starting from `origin`, see `cycle_lines`
+    pub(crate) fn cycles(&self, origin: i32, period: i32) -> Vec<Vec<String>> {
+        let kinds = [
+            cycle_lines(&self.move_, origin, period),
+            cycle_lines(&self.movex_, origin, period),
+            cycle_lines(&self.movey_, origin, period),
+            cycle_lines(&self.fade_, origin, period),
+            cycle_lines(&self.rotate_, origin, period),
+            cycle_lines(&self.scale_, origin, period),
+            cycle_lines(&self.scalevec_, origin, period),
+            cycle_lines(&self.color_, origin, period),
+            cycle_lines(&self.hflip_, origin, period),
+            cycle_lines(&self.vflip_, origin, period),
+            cycle_lines(&self.additive_, origin, period),
+        ];
+        let len = kinds.iter().map(Vec::len).max().unwrap_or(0);
+        (0..len)
+            .map(|cycle| {
+                kinds
+                    .iter()
+                    .flat_map(|kind| kind.get(cycle).into_iter().flatten().cloned())
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Splits the events in cycles, see `split_cycles`
+    pub(crate) fn split_cycles(
+        &self,
+        origin: i32,
+        period: i32,
+        count: i32,
+        depth: usize,
+    ) -> (Self, Self) {
+        let (move_, rest_move_) = split_cycles(&self.move_, origin, period, count, depth);
+        let (movex_, rest_movex_) = split_cycles(&self.movex_, origin, period, count, depth);
+        let (movey_, rest_movey_) = split_cycles(&self.movey_, origin, period, count, depth);
+        let (fade_, rest_fade_) = split_cycles(&self.fade_, origin, period, count, depth);
+        let (rotate_, rest_rotate_) = split_cycles(&self.rotate_, origin, period, count, depth);
+        let (scale_, rest_scale_) = split_cycles(&self.scale_, origin, period, count, depth);
+        let (scalevec_, rest_scalevec_) =
+            split_cycles(&self.scalevec_, origin, period, count, depth);
+        let (color_, rest_color_) = split_cycles(&self.color_, origin, period, count, depth);
+        let (hflip_, rest_hflip_) = split_cycles(&self.hflip_, origin, period, count, depth);
+        let (vflip_, rest_vflip_) = split_cycles(&self.vflip_, origin, period, count, depth);
+        let (additive_, rest_additive_) =
+            split_cycles(&self.additive_, origin, period, count, depth);
+        (
+            Self {
+                move_,
+                movex_,
+                movey_,
+                fade_,
+                rotate_,
+                scale_,
+                scalevec_,
+                color_,
+                hflip_,
+                vflip_,
+                additive_,
+            },
+            Self {
+                move_: rest_move_,
+                movex_: rest_movex_,
+                movey_: rest_movey_,
+                fade_: rest_fade_,
+                rotate_: rest_rotate_,
+                scale_: rest_scale_,
+                scalevec_: rest_scalevec_,
+                color_: rest_color_,
+                hflip_: rest_hflip_,
+                vflip_: rest_vflip_,
+                additive_: rest_additive_,
+            },
+        )
+    }
+
+    pub(crate) fn append(&mut self, other: &EventCollection) {
+        append_events(&mut self.move_, &other.move_);
+        append_events(&mut self.movex_, &other.movex_);
+        append_events(&mut self.movey_, &other.movey_);
+        append_events(&mut self.fade_, &other.fade_);
+        append_events(&mut self.rotate_, &other.rotate_);
+        append_events(&mut self.scale_, &other.scale_);
+        append_events(&mut self.scalevec_, &other.scalevec_);
+        append_events(&mut self.color_, &other.color_);
+        append_events(&mut self.hflip_, &other.hflip_);
+        append_events(&mut self.vflip_, &other.vflip_);
+        append_events(&mut self.additive_, &other.additive_);
+    }
+}
+
+// A `L` block repeating its events `loop_count` times from `start_time`
+//
+// The timestamps of the events are relative to `start_time`, one iteration lasting until the end
+// of the last event
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Loop {
+    start_time: i32,
+    loop_count: u32,
+    events: EventCollection,
+    raw_lines: Vec<String>,
+}
+
+impl Loop {
+    pub fn to_str(&self, depth: usize, precision: Option<usize>) -> String {
+        self.header(depth) + &self.events.to_str(precision) + &raw_lines_to_str(&self.raw_lines)
+    }
+
+    fn header(&self, depth: usize) -> String {
+        format!(
+            "{} L,{},{}\n",
+            " ".repeat(depth),
+            self.start_time,
+            self.loop_count
+        )
+    }
+
+    pub fn line_count(&self, precision: Option<usize>) -> usize {
+        1 + self.events.line_count(precision) + self.raw_lines.len()
+    }
+
+    pub fn byte_len(&self, depth: usize, precision: Option<usize>) -> usize {
+        self.header(depth).len() + self.events.byte_len(precision) + raw_lines_len(&self.raw_lines)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(
+        &self,
+        w: &mut W,
+        depth: usize,
+        precision: Option<usize>,
+    ) -> io::Result<()> {
+        w.write_all(self.header(depth).as_bytes())?;
+        self.events.write_to(w, precision)?;
+        write_raw_lines(w, &self.raw_lines)
+    }
+
+    // Earliest start time of an event strictly after `after`, every iteration included
+    pub fn next_start_time(&self, after: i32) -> Option<i32> {
+        let duration = self.events.end_time()?;
+        let after = after - self.start_time;
+        let iteration = if after < 0 || duration <= 0 {
+            0
+        } else {
+            after / duration
+        };
+        let in_iteration = self
+            .events
+            .next_start_time(after - iteration * duration)
+            .map(|time| (iteration, time));
+        let in_next_iteration = || Some((iteration + 1, self.events.start_time()?));
+        let (iteration, time) = in_iteration.or_else(in_next_iteration)?;
+        if iteration >= self.loop_count as i32 {
+            return None;
+        }
+        Some(self.start_time + iteration * duration + time)
+    }
+
+    // Timestamps at which the first iteration starts and the last one ends
+    pub fn bounds(&self) -> Option<(i32, i32)> {
+        let (start_time, end_time) = (self.events.start_time()?, self.events.end_time()?);
+        Some((
+            self.start_time + start_time,
+            self.start_time + self.loop_count as i32 * end_time,
+        ))
+    }
+
+    // The `Loop` keeping only its iterations over by `end`, or `None` if none of them is
+    pub fn truncated(&self, end: i32) -> Option<Self> {
+        let duration = self.events.end_time()?;
+        let loop_count = if duration > 0 {
+            ((end - self.start_time) / duration).min(self.loop_count as i32)
+        } else {
+            self.loop_count as i32
+        };
+        if loop_count <= 0 || self.start_time > end {
+            return None;
+        }
+        Some(Self {
+            start_time: self.start_time,
+            loop_count: loop_count as u32,
+            events: self.events.clone(),
+            raw_lines: self.raw_lines.clone(),
+        })
+    }
+
+    pub fn reversed(&self, total_duration: i32) -> Self {
+        let duration = self.events.end_time().unwrap_or(0);
+        Self {
+            start_time: total_duration - self.start_time - self.loop_count as i32 * duration,
+            loop_count: self.loop_count,
+            events: self.events.reversed(duration),
+            raw_lines: self.raw_lines.clone(),
+        }
+    }
+
+    // The events are relative to the start of the loop, only the loop itself is moved
+    pub fn shifted(&self, offset: i32) -> Self {
+        Self {
+            start_time: self.start_time + offset,
+            ..self.clone()
+        }
+    }
+
+    // The relative events are stretched around the start of the loop, and so is each iteration
+    pub fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        Self {
+            start_time: stretch_time(self.start_time, factor, pivot),
+            loop_count: self.loop_count,
+            events: self.events.stretched(factor, 0),
+            raw_lines: self.raw_lines.clone(),
+        }
+    }
+}
+
+// A `T` block playing its events whenever `trigger_type` fires between `start_time` and `end_time`
+//
+// The timestamps of the events are relative to the moment the trigger fires
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Trigger {
+    trigger_type: TriggerType,
+    start_time: i32,
+    end_time: i32,
+    events: EventCollection,
+    raw_lines: Vec<String>,
+}
+
+impl Trigger {
+    pub fn to_str(&self, depth: usize, precision: Option<usize>) -> String {
+        self.header(depth) + &self.events.to_str(precision) + &raw_lines_to_str(&self.raw_lines)
+    }
+
+    fn header(&self, depth: usize) -> String {
+        format!(
+            "{} T,{},{},{}\n",
+            " ".repeat(depth),
+            self.trigger_type,
+            self.start_time,
+            self.end_time
+        )
+    }
+
+    pub fn line_count(&self, precision: Option<usize>) -> usize {
+        1 + self.events.line_count(precision) + self.raw_lines.len()
+    }
+
+    pub fn byte_len(&self, depth: usize, precision: Option<usize>) -> usize {
+        self.header(depth).len() + self.events.byte_len(precision) + raw_lines_len(&self.raw_lines)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(
+        &self,
+        w: &mut W,
+        depth: usize,
+        precision: Option<usize>,
+    ) -> io::Result<()> {
+        w.write_all(self.header(depth).as_bytes())?;
+        self.events.write_to(w, precision)?;
+        write_raw_lines(w, &self.raw_lines)
+    }
+
+    // The `Trigger` cut so that it can only fire until `end`, or `None` if it starts after `end`
+    pub fn truncated(&self, end: i32) -> Option<Self> {
+        if self.start_time > end {
+            return None;
+        }
+        Some(Self {
+            end_time: self.end_time.min(end),
+            ..self.clone()
+        })
+    }
+
+    // The events played on firing are left untouched, only the window is reversed
+    pub fn reversed(&self, total_duration: i32) -> Self {
+        Self {
+            start_time: total_duration - self.end_time,
+            end_time: total_duration - self.start_time,
+            ..self.clone()
+        }
+    }
+
+    pub fn shifted(&self, offset: i32) -> Self {
+        Self {
+            start_time: self.start_time + offset,
+            end_time: self.end_time + offset,
+            ..self.clone()
+        }
+    }
+
+    // The relative events are stretched around the moment the trigger fires
+    pub fn stretched(&self, factor: f32, pivot: i32) -> Self {
+        Self {
+            trigger_type: self.trigger_type,
+            start_time: stretch_time(self.start_time, factor, pivot),
+            end_time: stretch_time(self.end_time, factor, pivot),
+            events: self.events.stretched(factor, 0),
+            raw_lines: self.raw_lines.clone(),
+        }
+    }
+}
+
+/// A `L` block of a [`Sprite`], returned by [`Sprite::loop_`]
+///
+/// The events pushed to a `SpriteLoop` are repeated by the loop, their timestamps being relative
+/// to the start time of the loop. One iteration lasts until the end of the last event, and the
+/// start and end times of the `Sprite` take every iteration into account.
+pub struct SpriteLoop<'a> {
+    sprite: &'a mut Sprite,
+    index: usize,
+}
+
+// Pushing an event of the kind `$kind` to the group of events `$group` of the sprite
+macro_rules! group_event {
+    ($group:ident, $name:ident, $kind:ident) => {
+        #[doc = concat!("Performs the event [`", stringify!($kind), "`] within the block")]
+        pub fn $name<T>(&mut self, args: T) -> &mut Self
+        where
+            T: Into<$kind>,
+        {
+            let mut event = args.into();
+            event.set_depth(self.sprite.current_depth + 1);
+            let (start, end) = (event.get_start_time(), event.get_end_time());
+            self.sprite.$group[self.index]
+                .events
+                .$name
+                .push(start..end, event);
+            self.sprite.update_bounds();
+            self
+        }
+    };
+}
+
+impl SpriteLoop<'_> {
+    group_event!(loops, move_, Move);
+    group_event!(loops, movex_, MoveX);
+    group_event!(loops, movey_, MoveY);
+    group_event!(loops, fade_, Fade);
+    group_event!(loops, rotate_, Rotate);
+    group_event!(loops, scale_, Scale);
+    group_event!(loops, scalevec_, ScaleVec);
+    group_event!(loops, color_, Color);
+    group_event!(loops, hflip_, HFlip);
+    group_event!(loops, vflip_, VFlip);
+    group_event!(loops, additive_, Additive);
+
+    /// Adds a line written verbatim after the commands of the block, see [`Sprite::push_raw`]
+    pub fn push_raw(&mut self, line: &str) -> &mut Self {
+        self.sprite.loops[self.index]
+            .raw_lines
+            .push(String::from(line));
+        self
+    }
+}
+
+/// A `T` block of a [`Sprite`], returned by [`Sprite::trigger_`]
+///
+/// The events pushed to a `SpriteTrigger` are played whenever the trigger fires, their timestamps
+/// being relative to that moment. As triggers may never fire, their events leave the start and
+/// end times of the `Sprite` untouched.
+pub struct SpriteTrigger<'a> {
+    sprite: &'a mut Sprite,
+    index: usize,
+}
+
+impl SpriteTrigger<'_> {
+    group_event!(triggers, move_, Move);
+    group_event!(triggers, movex_, MoveX);
+    group_event!(triggers, movey_, MoveY);
+    group_event!(triggers, fade_, Fade);
+    group_event!(triggers, rotate_, Rotate);
+    group_event!(triggers, scale_, Scale);
+    group_event!(triggers, scalevec_, ScaleVec);
+    group_event!(triggers, color_, Color);
+    group_event!(triggers, hflip_, HFlip);
+    group_event!(triggers, vflip_, VFlip);
+    group_event!(triggers, additive_, Additive);
+
+    /// Adds a line written verbatim after the commands of the block, see [`Sprite::push_raw`]
+    pub fn push_raw(&mut self, line: &str) -> &mut Self {
+        self.sprite.triggers[self.index]
+            .raw_lines
+            .push(String::from(line));
+        self
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SpriteType {
+    Sprite,
+    Animation {
+        frame_count: u32,
+        frame_delay: u32,
+        loop_type: LoopType,
+    },
+}
+
+/// The struct corresponding to sprites
+///
+/// Every method adding an event returns the `Sprite`, so that commands can be chained.
+///
+/// Example:
+/// ```
+/// use osb::Sprite;
+///
+/// let mut sprite = Sprite::new("res/sprite.png");
+/// sprite
+///     .fade_((0, 1000, 0, 1))
+///     .move_((0, 320, 240))
+///     .scale_((0, 1));
+/// assert_eq!(
+///     sprite.to_str(),
+///     "Sprite,Background,Centre,\"res/sprite.png\",320,240\n M,0,0,,320,240\n F,0,0,1000,0,1\n S,0,0,,1\n"
+/// );
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sprite {
+    events: EventCollection,
+    current_depth: usize,
+    path: String,
+    pos: Vec2,
+    layer: Layer,
+    origin: Origin,
+    color: Option<utils::Color>,
+    scale: Option<Number>,
+    precision: Option<usize>,
+    start_time: Option<i32>,
+    end_time: Option<i32>,
+    type_: SpriteType,
+    playfield: Option<bool>,
+    lints: Vec<Lint>,
+    loops: Vec<Loop>,
+    triggers: Vec<Trigger>,
+    raw_lines: Vec<String>,
+    depth: Option<usize>,
+}
+
+// Adding an event to a sprite
+macro_rules! add_event {
+    ($sprite:ident, $event:ident, $events:expr) => {
+        // Adjusting sprite's start and end values
+        let (event_start, event_end) = ($event.get_start_time(), $event.get_end_time());
+        match $sprite.start_time {
+            Some(sprite_start) => {
+                if event_start < sprite_start {
+                    $sprite.start_time = Some(event_start)
+                }
+            }
+            None => $sprite.start_time = Some(event_start),
+        }
+
+        match $sprite.end_time {
+            Some(sprite_end) => {
+                if sprite_end < event_end {
+                    $sprite.end_time = Some(event_end)
+                }
+            }
+            None => $sprite.end_time = Some(event_end),
+        }
+
+        // Pushing it to the events
+        $event.set_depth($sprite.current_depth);
+        $events.push(event_start..event_end, $event);
+    };
+}
+
+impl Sprite {
+    // A `Sprite` with no events and every setting at its default
+    fn with_path(path: String) -> Self {
+        Sprite {
+            events: EventCollection::new(),
+            current_depth: 0,
+            path,
+            pos: Vec2::from(320, 240),
+            layer: Layer::Background,
+            origin: Origin::Centre,
+            color: None,
+            scale: None,
+            precision: None,
+            start_time: None,
+            end_time: None,
+            type_: SpriteType::Sprite,
+            playfield: None,
+            lints: Vec::new(),
+            loops: Vec::new(),
+            triggers: Vec::new(),
+            raw_lines: Vec::new(),
+            depth: None,
+        }
+    }
+
+    /// Initializes a new `Sprite` or an animation `Sprite`
+    ///
+    /// See [trait implementations](#trait-implementations) to see how you can create a Sprite element
+    pub fn new<T>(args: T) -> Self
+    where
+        T: Into<Sprite>,
+    {
+        args.into()
+    }
+
+    /// Performs the event [`Move`] to a `Sprite`
     ///
     /// ```
     /// use osb::{Sprite, Easing, utils::Vec2};
-    /// use std::f32::consts::PI;
     ///
     /// let mut sprite = Sprite::new("res/sprite.png");
-    /// sprite.rotate_((0, PI));
+    ///
+    /// // There's a `Vec2` type you can use if you wish
+    /// sprite.move_((Easing::Out, 0, 1000, Vec2::from(0, 0), Vec2::from(320, 240)));
+    /// // But you're not forced to! Giving pairs of integers automatically translates to a `Vec2`
+    /// sprite.move_((Easing::QuadInOut, 1000, 2000, 320, 240, 100, 100));
+    /// // And of course you can use a static move too
+    /// sprite.move_((3000, Vec2::from(320, 240)));
     /// // Please refer to the trait implementations of the event to see everything you can do
     /// ```
-    pub fn rotate_<T>(&mut self, args: T)
+    pub fn move_<T>(&mut self, args: T) -> &mut Self
     where
-        T: Into<Rotate>,
+        T: Into<Move>,
     {
         let mut event = args.into();
-        add_event!(self, event, self.events.rotate_);
+        self.lint_move(&event);
+        add_event!(self, event, self.events.move_);
+        self
     }
 
-    /// Performs the event [`Scale`] to a `Sprite`
+    /// Performs a [`Move`] event from `from` to `to` baked into `segments` linear `Move` events
+    /// approximating `easing`
+    ///
+    /// This comes handy when targeting clients whose support of some easings is questionable. The
+    /// `segments` events are evenly spread between `start` and `end`, and nothing is done if
+    /// `segments` is zero.
+    ///
+    /// ```
+    /// use osb::{utils::Vec2, Easing, Sprite};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.bake_move(
+    ///     Easing::QuadIn,
+    ///     0,
+    ///     1000,
+    ///     Vec2::from(0, 0),
+    ///     Vec2::from(320, 240),
+    ///     2,
+    /// );
+    /// assert!(sprite.to_str().contains(" M,0,0,500,0,0,80,60\n"));
+    /// assert!(sprite.to_str().contains(" M,0,500,1000,80,60,320,240\n"));
+    /// ```
+    pub fn bake_move(
+        &mut self,
+        easing: Easing,
+        start: i32,
+        end: i32,
+        from: Vec2,
+        to: Vec2,
+        segments: usize,
+    ) -> &mut Self {
+        let time_at = |i: usize| start + ((end - start) as i64 * i as i64 / segments as i64) as i32;
+        let pos_at = |i: usize| match i {
+            0 => from,
+            i if i == segments => to,
+            i => from.lerp(to, easing.progress(time_at(i), start, end)),
+        };
+        for i in 0..segments {
+            self.move_((time_at(i), time_at(i + 1), pos_at(i), pos_at(i + 1)));
+        }
+        self
+    }
+
+    /// Performs the event [`MoveX`] to a `Sprite`
     ///
     /// ```
     /// use osb::{Sprite, Easing, utils::Vec2};
     ///
     /// let mut sprite = Sprite::new("res/sprite.png");
-    /// sprite.scale_((0, 1));
+    /// sprite.movex_((0, 320));
     /// // Please refer to the trait implementations of the event to see everything you can do
     /// ```
-    pub fn scale_<T>(&mut self, args: T)
+    pub fn movex_<T>(&mut self, args: T) -> &mut Self
     where
-        T: Into<Scale>,
+        T: Into<MoveX>,
     {
         let mut event = args.into();
-        add_event!(self, event, self.events.scale_);
+        add_event!(self, event, self.events.movex_);
+        self
     }
 
-    /// Performs the event [`ScaleVec`] to a `Sprite`
+    /// Performs the event [`MoveY`] to a `Sprite`
+    ///
+    /// ```
+    /// use osb::{Sprite, Easing, utils::Vec2};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.movey_((0, 240));
+    /// // Please refer to the trait implementations of the event to see everything you can do
+    /// ```
+    pub fn movey_<T>(&mut self, args: T) -> &mut Self
+    where
+        T: Into<MoveY>,
+    {
+        let mut event = args.into();
+        add_event!(self, event, self.events.movey_);
+        self
+    }
+
+    /// Performs the event [`Fade`] to a `Sprite`
+    ///
+    /// ```
+    /// use osb::{Sprite, Easing, utils::Vec2};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.fade_((0, 1));
+    /// // Please refer to the trait implementations of the event to see everything you can do
+    /// ```
+    pub fn fade_<T>(&mut self, args: T) -> &mut Self
+    where
+        T: Into<Fade>,
+    {
+        let mut event = args.into();
+        add_event!(self, event, self.events.fade_);
+        self
+    }
+
+    /// Performs the event [`Rotate`] to a `Sprite`
+    ///
+    /// ```
+    /// use osb::{Sprite, Easing, utils::Vec2};
+    /// use std::f32::consts::PI;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.rotate_((0, PI));
+    /// // Please refer to the trait implementations of the event to see everything you can do
+    /// ```
+    pub fn rotate_<T>(&mut self, args: T) -> &mut Self
+    where
+        T: Into<Rotate>,
+    {
+        let mut event = args.into();
+        add_event!(self, event, self.events.rotate_);
+        self
+    }
+
+    /// Performs the event [`Scale`] to a `Sprite`
+    ///
+    /// ```
+    /// use osb::{Sprite, Easing, utils::Vec2};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.scale_((0, 1));
+    /// // Please refer to the trait implementations of the event to see everything you can do
+    /// ```
+    pub fn scale_<T>(&mut self, args: T) -> &mut Self
+    where
+        T: Into<Scale>,
+    {
+        let mut event = args.into();
+        add_event!(self, event, self.events.scale_);
+        self
+    }
+
+    /// Performs the event [`ScaleVec`] to a `Sprite`
     ///
     /// ```
     /// use osb::{Sprite, Easing, utils::Vec2};
@@ -257,12 +1522,13 @@ impl Sprite {
     /// sprite.scalevec_((3000, Vec2::from(1, 0.5)));
     /// // Please refer to the trait implementations of the event to see everything you can do
     /// ```
-    pub fn scalevec_<T>(&mut self, args: T)
+    pub fn scalevec_<T>(&mut self, args: T) -> &mut Self
     where
         T: Into<ScaleVec>,
     {
         let mut event = args.into();
         add_event!(self, event, self.events.scalevec_);
+        self
     }
 
     /// Performs the event [`Color`] to a `Sprite`
@@ -279,12 +1545,53 @@ impl Sprite {
     /// sprite.color_((3000, Color::green()));
     /// // Please refer to the trait implementations of the event to see everything you can do
     /// ```
-    pub fn color_<T>(&mut self, args: T)
+    pub fn color_<T>(&mut self, args: T) -> &mut Self
     where
         T: Into<Color>,
     {
         let mut event = args.into();
         add_event!(self, event, self.events.color_);
+        self
+    }
+
+    /// Performs a chained [`Color`] event to a `Sprite`, transitioning through every color of
+    /// `colors` in turn from `start_time` on, each transition lasting `step` milliseconds
+    ///
+    /// The transitions are emitted as a single line, which is much more compact than a `Color`
+    /// event per transition. A single color is emitted as a static `Color` event, and nothing is
+    /// done if `colors` is empty.
+    ///
+    /// ```
+    /// use osb::{utils::Color, Easing, Sprite};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.color_keyframes(
+    ///     Easing::Linear,
+    ///     0,
+    ///     500,
+    ///     &[Color::red(), Color::green(), Color::blue()],
+    /// );
+    /// assert!(sprite.to_str().contains(" C,0,0,500,255,0,0,0,255,0,0,0,255\n"));
+    /// assert_eq!(sprite.end_time(), Some(1000));
+    /// ```
+    pub fn color_keyframes(
+        &mut self,
+        easing: Easing,
+        start_time: i32,
+        step: i32,
+        colors: &[utils::Color],
+    ) -> &mut Self {
+        match colors {
+            [] => self,
+            [color] => self.color_((start_time, *color)),
+            colors => self.color_(Color::Chained(
+                0,
+                easing,
+                start_time,
+                start_time + step,
+                colors.to_vec(),
+            )),
+        }
     }
 
     /// Performs the event [`HFlip`] to a `Sprite`
@@ -296,12 +1603,13 @@ impl Sprite {
     /// sprite.hflip_((0, 1000));
     /// // Please refer to the trait implementations of the event to see everything you can do
     /// ```
-    pub fn hflip_<T>(&mut self, args: T)
+    pub fn hflip_<T>(&mut self, args: T) -> &mut Self
     where
         T: Into<HFlip>,
     {
         let mut event = args.into();
         add_event!(self, event, self.events.hflip_);
+        self
     }
 
     /// Performs the event [`VFlip`] to a `Sprite`
@@ -313,12 +1621,13 @@ impl Sprite {
     /// sprite.vflip_((0, 1000));
     /// // Please refer to the trait implementations of the event to see everything you can do
     /// ```
-    pub fn vflip_<T>(&mut self, args: T)
+    pub fn vflip_<T>(&mut self, args: T) -> &mut Self
     where
         T: Into<VFlip>,
     {
         let mut event = args.into();
         add_event!(self, event, self.events.vflip_);
+        self
     }
 
     /// Performs the event [`Additive`] to a `Sprite`
@@ -330,203 +1639,1539 @@ impl Sprite {
     /// sprite.additive_((0, 1000));
     /// // Please refer to the trait implementations of the event to see everything you can do
     /// ```
-    pub fn additive_<T>(&mut self, args: T)
+    pub fn additive_<T>(&mut self, args: T) -> &mut Self
     where
         T: Into<Additive>,
     {
         let mut event = args.into();
         add_event!(self, event, self.events.additive_);
+        self
     }
 
-    /// Returns the initial X position of a `Sprite`
+    /// Performs the event [`Additive`] over the whole lifetime of a `Sprite`, from its
+    /// [`start_time`](Sprite::start_time) to its [`end_time`](Sprite::end_time)
     ///
-    /// **Warning**: This does **not** return the X position in a certain time.
+    /// Does nothing if the `Sprite` has no events yet.
     ///
-    /// Example:
     /// ```
-    /// use osb::{utils::Number, Sprite};
+    /// use osb::Sprite;
+    ///
     /// let mut sprite = Sprite::new("res/sprite.png");
-    /// sprite.move_((0, 100, 100));
-    /// assert_eq!(sprite.get_x(), Number::Int(320));
+    /// sprite.move_((0, 1000, 0, 0, 320, 240)).additive();
+    /// assert!(sprite.to_str().contains(" P,0,0,1000,A\n"));
     /// ```
-    pub fn get_x(&self) -> Number {
-        self.pos.x
+    pub fn additive(&mut self) -> &mut Self {
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            self.additive_((start_time, end_time));
+        }
+        self
     }
 
-    /// Returns the initial Y position of a `Sprite`
+    /// Removes every event of the `Sprite` and returns them, the loops and triggers of the `Sprite`
+    /// being kept
     ///
-    /// **Warning**: This does **not** return the Y position in a certain time.
+    /// The start and end times of the `Sprite` are updated accordingly.
     ///
     /// Example:
     /// ```
-    /// use osb::{utils::Number, Sprite};
+    /// use osb::Sprite;
+    ///
     /// let mut sprite = Sprite::new("res/sprite.png");
-    /// sprite.move_((0, 100, 100));
-    /// assert_eq!(sprite.get_y(), Number::Int(240));
+    /// sprite.fade_((0, 1000, 0, 1));
+    ///
+    /// let mut other = Sprite::new("res/other.png");
+    /// other.set_events(sprite.take_events());
+    /// assert_eq!(sprite.end_time(), None);
+    /// assert_eq!(other.end_time(), Some(1000));
     /// ```
-    pub fn get_y(&self) -> Number {
-        self.pos.y
+    pub fn take_events(&mut self) -> EventCollection {
+        let events = core::mem::take(&mut self.events);
+        self.update_bounds();
+        events
     }
 
-    /// Returns the start time of the first event of a `Sprite`
+    /// Replaces the events of the `Sprite` by `events`, the loops and triggers of the `Sprite`
+    /// being kept
+    ///
+    /// The start and end times of the `Sprite` are updated accordingly. See
+    /// [`Sprite::take_events`].
+    pub fn set_events(&mut self, events: EventCollection) {
+        self.events = events;
+        self.update_bounds();
+    }
+
+    /// Removes every event of the kind `kind` from the `Sprite`, the loops and triggers of the
+    /// `Sprite` being kept
+    ///
+    /// The start and end times of the `Sprite` are recomputed from its remaining events.
     ///
     /// Example:
     /// ```
-    /// use osb::Sprite;
+    /// use osb::{EventKind, Sprite};
     ///
     /// let mut sprite = Sprite::new("res/sprite.png");
-    /// assert_eq!(sprite.start_time(), None);
-    ///
-    /// sprite.move_((100, 200, 0, 0, 320, 240));
-    /// assert_eq!(sprite.start_time(), Some(100));
+    /// sprite.fade_((0, 2000, 0, 1)).move_((500, 1000, 0, 0, 320, 240));
     ///
-    /// sprite.fade_((0, 100, 0, 1));
-    /// assert_eq!(sprite.start_time(), Some(0));
+    /// sprite.clear(EventKind::Fade);
+    /// assert!(!sprite.to_str().contains(" F,"));
+    /// assert_eq!(sprite.start_time(), Some(500));
+    /// assert_eq!(sprite.end_time(), Some(1000));
     /// ```
-    pub fn start_time(&self) -> Option<i32> {
-        self.start_time
+    pub fn clear(&mut self, kind: EventKind) -> &mut Self {
+        self.events.clear(kind);
+        self.update_bounds();
+        self
     }
 
-    /// Returns the end time of the first event of a `Sprite`
+    /// Cuts the events of the `Sprite` so that none of them ends after the timestamp `max`, which
+    /// comes handy when a generator overshoots the length of the song
+    ///
+    /// Events starting after `max` are dropped, and dynamic events still in progress at `max` keep
+    /// their easing but end early, on the value they had at `max`. Loops only keep the iterations
+    /// over by `max`. The start and end times of the `Sprite` are updated accordingly.
     ///
     /// Example:
     /// ```
     /// use osb::Sprite;
     ///
     /// let mut sprite = Sprite::new("res/sprite.png");
-    /// assert_eq!(sprite.end_time(), None);
-    ///
-    /// sprite.move_((0, 100, 0, 0, 320, 240));
-    /// assert_eq!(sprite.end_time(), Some(100));
+    /// sprite.move_((0, 2000, 0, 0, 320, 240));
+    /// sprite.fade_((1800, 1));
     ///
-    /// sprite.fade_((100, 200, 1, 0));
-    /// assert_eq!(sprite.end_time(), Some(200));
+    /// sprite.clamp_times(1500);
+    /// assert!(sprite.to_str().contains(" M,0,0,1500,0,0,240,180\n"));
+    /// assert!(!sprite.to_str().contains(" F,"));
+    /// assert_eq!(sprite.end_time(), Some(1500));
     /// ```
-    pub fn end_time(&self) -> Option<i32> {
-        self.end_time
+    pub fn clamp_times(&mut self, max: i32) -> &mut Self {
+        self.events = self.events.truncated(max);
+        self.loops = self
+            .loops
+            .iter()
+            .filter_map(|loop_| loop_.truncated(max))
+            .collect();
+        self.triggers = self
+            .triggers
+            .iter()
+            .filter_map(|trigger| trigger.truncated(max))
+            .collect();
+        self.update_bounds();
+        self
     }
 
-    /// Returns the contents of the `Sprite`
+    /// Moves every event of the `Sprite` by `delta` milliseconds, later if `delta` is positive and
+    /// earlier otherwise, which comes handy to reuse an animation at another point of the song
     ///
-    /// **Warning**: this method is not meant to be used
-    pub fn to_str(&self) -> String {
-        match &self.type_ {
-            SpriteType::Sprite => {
-                return format!(
-                    "Sprite,{},{},\"{}\",{},{}\n{}",
-                    self.layer,
-                    self.origin,
-                    self.path,
-                    self.pos.x,
-                    self.pos.y,
-                    self.events.to_str()
-                );
-            }
-            SpriteType::Animation {
-                frame_count,
-                frame_delay,
-                loop_type,
-            } => {
-                return format!(
-                    "Animation,{},{},\"{}\",{},{},{},{}{}\n{}",
-                    self.layer,
-                    self.origin,
-                    self.path,
-                    self.pos.x,
-                    self.pos.y,
-                    frame_count,
-                    frame_delay,
-                    match loop_type {
-                        LoopType::LoopOnce => ",LoopOnce",
-                        // defaults to LoopForever if not specified
-                        LoopType::LoopForever => "",
-                    },
-                    self.events.to_str()
-                );
+    /// Loops and triggers are moved as a whole, and the start and end times of the `Sprite` are
+    /// updated accordingly.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240));
+    /// sprite.fade_((500, 1));
+    ///
+    /// sprite.shift_time(2000);
+    /// assert!(sprite.to_str().contains(" M,0,2000,3000,0,0,320,240\n"));
+    /// assert!(sprite.to_str().contains(" F,0,2500,,1\n"));
+    /// assert_eq!(sprite.start_time(), Some(2000));
+    /// assert_eq!(sprite.end_time(), Some(3000));
+    /// ```
+    pub fn shift_time(&mut self, delta: i32) -> &mut Self {
+        self.events = self.events.shifted(delta);
+        self.loops = self
+            .loops
+            .iter()
+            .map(|loop_| loop_.shifted(delta))
+            .collect();
+        self.triggers = self
+            .triggers
+            .iter()
+            .map(|trigger| trigger.shifted(delta))
+            .collect();
+        for lint in &mut self.lints {
+            match lint {
+                Lint::OffScreen { time, .. } => *time += delta,
             }
         }
+        self.update_bounds();
+        self
     }
 
-    /// Sets the [`Layer`] of the `Sprite`
+    /// Stretches the events of the `Sprite` around the timestamp `pivot`, each timestamp `t`
+    /// becoming `pivot + (t - pivot) * factor` truncated to the millisecond, which comes handy to
+    /// retime a section to another BPM
     ///
-    /// **Warning**: this method is not meant to be used
-    pub fn set_layer(&mut self, layer: Layer) {
-        self.layer = layer;
+    /// A `factor` greater than 1 slows the animation down and a `factor` between 0 and 1 speeds it
+    /// up. Loops and triggers are stretched as well, and the start and end times of the `Sprite`
+    /// are updated accordingly. See [`Event::stretched`].
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((1000, 2000, 0, 0, 320, 240));
+    /// sprite.fade_((3000, 1));
+    ///
+    /// sprite.scale_time(1.5, 1000);
+    /// assert!(sprite.to_str().contains(" M,0,1000,2500,0,0,320,240\n"));
+    /// assert!(sprite.to_str().contains(" F,0,4000,,1\n"));
+    /// assert_eq!(sprite.end_time(), Some(4000));
+    /// ```
+    pub fn scale_time(&mut self, factor: f32, pivot: i32) -> &mut Self {
+        self.events = self.events.stretched(factor, pivot);
+        self.loops = self
+            .loops
+            .iter()
+            .map(|loop_| loop_.stretched(factor, pivot))
+            .collect();
+        self.triggers = self
+            .triggers
+            .iter()
+            .map(|trigger| trigger.stretched(factor, pivot))
+            .collect();
+        for lint in &mut self.lints {
+            match lint {
+                Lint::OffScreen { time, .. } => *time = stretch_time(*time, factor, pivot),
+            }
+        }
+        self.update_bounds();
+        self
     }
-}
 
-/// Creates a `Sprite` with the path of the file
-///
-/// Example:
-/// ```
-/// use osb::Sprite;
-/// let path = String::from("res/sprite.png");
-/// let mut sprite = Sprite::new(path);
-/// ```
-impl Into<Sprite> for String {
-    fn into(self) -> Sprite {
-        Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self,
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+    /// Merges the runs of static events of the `Sprite` into linear events, which comes handy
+    /// when events are generated frame by frame
+    ///
+    /// For each kind of event, every stretch of at least three consecutive static events lying on
+    /// the same line, or of at least two identical ones, becomes a single linear event going from
+    /// the first of them to the last one. Values are compared once rendered with the precision of
+    /// the `Sprite`, see [`Sprite::set_precision`]. Parameter events, such as [`HFlip`], are left
+    /// as they are, and so are the static events happening during another event of the same kind.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// for frame in 0..=10 {
+    ///     sprite.movex_((frame * 100, frame * 10));
+    /// }
+    /// assert_eq!(sprite.line_count(), 12);
+    ///
+    /// sprite.optimize();
+    /// assert_eq!(sprite.line_count(), 2);
+    /// assert!(sprite.to_str().contains(" MX,0,0,1000,0,100\n"));
+    /// ```
+    pub fn optimize(&mut self) {
+        let decimals = self.precision.unwrap_or(DEFAULT_PRECISION);
+        self.events = self.events.optimized(decimals);
+        for loop_ in &mut self.loops {
+            loop_.events = loop_.events.optimized(decimals);
+        }
+        for trigger in &mut self.triggers {
+            trigger.events = trigger.events.optimized(decimals);
         }
+        self.update_bounds();
     }
-}
 
-/// Creates a `Sprite` with the path of the file
-///
-/// Example:
-/// ```
-/// use osb::Sprite;
-/// let path = "res/sprite.png";
-/// let mut sprite = Sprite::new(path);
-/// ```
-impl Into<Sprite> for &str {
-    fn into(self) -> Sprite {
-        Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self),
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+    /// Removes the events identical to an earlier event of the same kind, keeping one of each
+    ///
+    /// Events are identical when their times, easings, values and depths are all equal, which
+    /// happens when generators layer the same event several times.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.fade_((0, 1000, 0, 1)).fade_((0, 1000, 0, 1));
+    /// assert_eq!(sprite.event_kind_counts().fade, 2);
+    ///
+    /// sprite.dedup_events();
+    /// assert_eq!(sprite.event_kind_counts().fade, 1);
+    /// ```
+    pub fn dedup_events(&mut self) {
+        self.events = self.events.deduped();
+        for loop_ in &mut self.loops {
+            loop_.events = loop_.events.deduped();
+        }
+        for trigger in &mut self.triggers {
+            trigger.events = trigger.events.deduped();
         }
     }
-}
 
-/// Creates a `Sprite` with the origin and path of the file
-///
-/// Example:
-/// ```
-/// use osb::{Origin, Sprite};
-/// let origin = Origin::Centre;
-/// let path = String::from("res/sprite.png");
-/// let mut sprite = Sprite::new((origin, path));
-/// ```
-impl Into<Sprite> for (Origin, String) {
-    fn into(self) -> Sprite {
-        Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.1,
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
-            origin: self.0,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+    // Recomputes the start and end times of the `Sprite` from its events and loops
+    fn update_bounds(&mut self) {
+        let bounds = self
+            .events
+            .start_time()
+            .zip(self.events.end_time())
+            .into_iter()
+            .chain(self.loops.iter().filter_map(Loop::bounds));
+        let (mut start_time, mut end_time) = (None, None);
+        for (start, end) in bounds {
+            start_time = Some(start_time.map_or(start, |time: i32| time.min(start)));
+            end_time = Some(end_time.map_or(end, |time: i32| time.max(end)));
         }
+        self.start_time = start_time;
+        self.end_time = end_time;
     }
-}
+
+    /// Sets the initial [`Color`](utils::Color) of a `Sprite`
+    ///
+    /// The `Sprite` is then tinted from its very first event on: a static [`Color`] event is
+    /// emitted at its start time, or at the timestamp 0 if it has no events.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Color, Sprite};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png").with_color(Color::red());
+    /// sprite.fade_((1000, 2000, 0, 1));
+    /// assert!(sprite.to_str().contains(" C,0,1000,,255,0,0\n"));
+    /// ```
+    pub fn with_color(mut self, color: utils::Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the initial scale of a `Sprite`
+    ///
+    /// The `Sprite` is then scaled from its very first event on: a static [`Scale`] event is
+    /// emitted at its start time, or at the timestamp 0 if it has no events, ahead of every other
+    /// scale event.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.set_initial_scale(0.5.into());
+    /// sprite.fade_((1000, 2000, 0, 1));
+    /// assert!(sprite.to_str().contains(" S,0,1000,,0.5\n"));
+    /// ```
+    pub fn set_initial_scale(&mut self, scale: Number) {
+        self.scale = Some(scale);
+    }
+
+    /// Sets the number of decimal places the values of the events of a `Sprite` are rounded to
+    /// when rendered
+    ///
+    /// Values are written with [`DEFAULT_PRECISION`] decimal places by default. Rounding them
+    /// further shortens the output, while sub-pixel effects can keep more decimals than the rest of
+    /// the storyboard.
+    /// The events themselves are left untouched.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.set_precision(2);
+    /// sprite.move_((0, 1000, 0.123456, 0, 320.5, 240.999));
+    /// assert!(sprite.to_str().contains(" M,0,0,1000,0.12,0,320.5,241\n"));
+    /// ```
+    pub fn set_precision(&mut self, decimals: usize) {
+        self.precision = Some(decimals);
+    }
+
+    /// Sets the initial position of a `Sprite`
+    ///
+    /// Whole-valued coordinates are stored as integers so that the declaration line stays clean.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Number, Sprite};
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.set_position(100.0, 200.5);
+    /// assert_eq!(sprite.get_x(), Number::Int(100));
+    /// assert_eq!(sprite.get_y(), Number::Float(200.5));
+    /// ```
+    pub fn set_position<T, U>(&mut self, x: T, y: U)
+    where
+        T: Into<Number>,
+        U: Into<Number>,
+    {
+        self.pos = Vec2::from(x, y).normalized();
+    }
+
+    /// Adds a line written verbatim after the commands of the `Sprite`
+    ///
+    /// This keeps the lines `osb` doesn't model, such as commands it doesn't know about, so that
+    /// they survive a round trip. The line must hold its own indentation, and it is kept as is by
+    /// every method transforming the `Sprite`.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.push_raw(" X,0,0,1000,42");
+    /// assert!(sprite.to_str().ends_with("\n X,0,0,1000,42\n"));
+    /// ```
+    pub fn push_raw(&mut self, line: &str) {
+        self.raw_lines.push(String::from(line));
+    }
+
+    /// Reports a [`Lint::OffScreen`] for every position given to the next [`Move`] events lying
+    /// outside of the visible playfield
+    ///
+    /// The playfield spans `0..=640` horizontally, or `-107..=747` if `widescreen` is set, and
+    /// `0..=480` vertically. Coordinates are never altered, see [`Sprite::lint`] to retrieve the
+    /// reported `Lint`s.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.set_clamp_to_playfield(false);
+    /// sprite.move_((0, 1000, 320, 240, 700, 240));
+    /// assert_eq!(sprite.lint().len(), 1);
+    /// ```
+    pub fn set_clamp_to_playfield(&mut self, widescreen: bool) {
+        self.playfield = Some(widescreen);
+    }
+
+    /// Returns the [`Lint`]s reported while pushing events to the `Sprite`
+    pub fn lint(&self) -> &[Lint] {
+        &self.lints
+    }
+
+    /// Checks the `Sprite` as a whole for common mistakes, returning every [`SpriteWarning`]
+    /// found
+    ///
+    /// A `Sprite` with events but without any [`Fade`] event, or with events outside of the window
+    /// going from its first [`Fade`] event to its last one, is reported, and so is every event
+    /// ending before it starts. The [`Fade`] events of triggers are left out of the window as
+    /// triggers may never fire.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Sprite, SpriteWarning};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240));
+    /// assert_eq!(sprite.validate(), Err(vec![SpriteWarning::NoFade]));
+    ///
+    /// sprite.fade_((0, 1000, 0, 1));
+    /// assert_eq!(sprite.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<SpriteWarning>> {
+        let mut warnings = Vec::new();
+
+        let fade_bounds = self
+            .events
+            .fade_
+            .points
+            .first()
+            .zip(self.events.fade_.points.last())
+            .map(|((start, _), (end, _))| (*start, *end))
+            .into_iter()
+            .chain(
+                self.loops
+                    .iter()
+                    .filter(|loop_| !loop_.events.fade_.points.is_empty())
+                    .filter_map(Loop::bounds),
+            );
+        let mut fade_window: Option<(i32, i32)> = None;
+        for (start, end) in fade_bounds {
+            fade_window = Some(fade_window.map_or((start, end), |(fade_start, fade_end)| {
+                (fade_start.min(start), fade_end.max(end))
+            }));
+        }
+
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            match fade_window {
+                Some((fade_start, fade_end)) if start_time < fade_start || end_time > fade_end => {
+                    warnings.push(SpriteWarning::BeyondFade {
+                        start_time: fade_start,
+                        end_time: fade_end,
+                    })
+                }
+                None if self.event_kind_counts().fade == 0 => warnings.push(SpriteWarning::NoFade),
+                _ => {}
+            }
+        }
+
+        warnings.extend(self.events.backwards_events().into_iter().map(
+            |(start_time, end_time)| SpriteWarning::EndBeforeStart {
+                start_time,
+                end_time,
+            },
+        ));
+
+        if warnings.is_empty() {
+            Ok(())
+        } else {
+            Err(warnings)
+        }
+    }
+
+    fn lint_move(&mut self, event: &Move) {
+        let widescreen = match self.playfield {
+            Some(widescreen) => widescreen,
+            None => return,
+        };
+        let positions = match event {
+            Move::Static(_, time, pos) => vec![(*time, *pos)],
+            Move::Dynamic(_, _, start_time, end_time, start_pos, end_pos) => {
+                vec![(*start_time, *start_pos), (*end_time, *end_pos)]
+            }
+        };
+        for (time, pos) in positions {
+            if !lint::is_on_screen(pos, widescreen) {
+                self.lints.push(Lint::OffScreen { time, pos });
+            }
+        }
+    }
+
+    /// Returns the initial X position of a `Sprite`
+    ///
+    /// **Warning**: This does **not** return the X position in a certain time.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Number, Sprite};
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 100, 100));
+    /// assert_eq!(sprite.get_x(), Number::Int(320));
+    /// ```
+    pub fn get_x(&self) -> Number {
+        self.pos.x
+    }
+
+    /// Returns the initial Y position of a `Sprite`
+    ///
+    /// **Warning**: This does **not** return the Y position in a certain time.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Number, Sprite};
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 100, 100));
+    /// assert_eq!(sprite.get_y(), Number::Int(240));
+    /// ```
+    pub fn get_y(&self) -> Number {
+        self.pos.y
+    }
+
+    /// Returns the position, scale, rotation, opacity and colorization of the `Sprite` at the
+    /// timestamp `time`
+    ///
+    /// Every property is resolved from the events of its kind, a `Sprite` having no event of a
+    /// kind keeping its default value: its initial position, a scale of `1`, no rotation, full
+    /// opacity and its [initial color](Sprite::with_color), or white.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Vec2, Sprite};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 100, 200)).fade_((0, 1000, 0, 1));
+    ///
+    /// let transform = sprite.transform_at(500);
+    /// assert_eq!(transform.pos, Vec2::from(50, 100));
+    /// assert_eq!(transform.opacity, 0.5);
+    /// ```
+    pub fn transform_at(&self, time: i32) -> Transform {
+        Transform {
+            pos: self.pos_at(time),
+            scale: self.scale_vec_at(time),
+            rotation: event_at(&self.events.rotate_, time)
+                .map_or(0., |rotate| rotate.value_at(time).as_f32()),
+            opacity: event_at(&self.events.fade_, time)
+                .map_or(1., |fade| fade.value_at(time).as_f32()),
+            color: event_at(&self.events.color_, time).map_or_else(
+                || self.color.unwrap_or_else(utils::Color::white),
+                |color| color.value_at(time),
+            ),
+        }
+    }
+
+    /// Returns the position of the `Sprite` at the timestamp `time`
+    ///
+    /// The active events are looked up in the [`IntervalMap`]s of the `Sprite` and eased to
+    /// `time`, an event over by `time` holding its end value.
+    ///
+    /// Each axis is controlled by the command with the latest start time among the [`Move`]
+    /// events and the [`MoveX`] (or [`MoveY`]) ones started by `time`, a `MoveX` or `MoveY` event
+    /// winning over a `Move` event starting at the same time. A `MoveX` event thus overrides the
+    /// x-axis of a `Move` event started before it, even while that `Move` event is still running,
+    /// and is overridden in turn by the next `Move` event. Before its first move event, the
+    /// `Sprite` is at its initial position.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Vec2, Sprite};
+    ///
+    /// let mut sprite = Sprite::new(("res/sprite.png", Vec2::from(320, 240)));
+    /// sprite.move_((1000, 2000, 0, 0, 100, 200));
+    /// sprite.movex_((1500, 400));
+    ///
+    /// assert_eq!(sprite.pos_at(0), Vec2::from(320, 240));
+    /// assert_eq!(sprite.pos_at(1250), Vec2::from(25, 50));
+    /// assert_eq!(sprite.pos_at(1500), Vec2::from(400, 100));
+    /// ```
+    pub fn pos_at(&self, time: i32) -> Vec2 {
+        let started_move = event_at(&self.events.move_, time)
+            .filter(|event| event.get_start_time() <= time)
+            .map(|event| (event.get_start_time(), event.value_at(time)));
+        let axis_at =
+            |axis: Option<(i32, Number)>, from_move: Option<(i32, Number)>| match (axis, from_move)
+            {
+                (Some((axis_start, value)), Some((move_start, _))) if axis_start >= move_start => {
+                    Some(value)
+                }
+                (_, Some((_, value))) => Some(value),
+                (axis, None) => axis.map(|(_, value)| value),
+            };
+
+        let x = event_at(&self.events.movex_, time)
+            .filter(|event| event.get_start_time() <= time)
+            .map(|event| (event.get_start_time(), event.value_at(time)));
+        let y = event_at(&self.events.movey_, time)
+            .filter(|event| event.get_start_time() <= time)
+            .map(|event| (event.get_start_time(), event.value_at(time)));
+        Vec2::from(
+            axis_at(x, started_move.map(|(start, pos)| (start, pos.x))).unwrap_or(self.pos.x),
+            axis_at(y, started_move.map(|(start, pos)| (start, pos.y))).unwrap_or(self.pos.y),
+        )
+    }
+
+    /// Returns the smallest and the largest coordinates reached by the `Sprite` over its
+    /// lifetime, or `None` if it has no [`Move`], [`MoveX`] or [`MoveY`] event
+    ///
+    /// Only the start and end positions of the events are taken into account, including the ones
+    /// of loops and triggers, so easings overshooting their end values, such as
+    /// [`Easing::BackOut`], may go past the returned box. An axis without any event is at the
+    /// initial position of the `Sprite`. The scale of the `Sprite` is ignored.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Vec2, Sprite};
+    ///
+    /// let mut sprite = Sprite::new(("res/sprite.png", Vec2::from(320, 240)));
+    /// assert_eq!(sprite.bounds(), None);
+    ///
+    /// sprite.move_((0, 1000, 100, 200, 300, 100));
+    /// sprite.movex_((1000, 2000, 300, 500));
+    /// assert_eq!(
+    ///     sprite.bounds(),
+    ///     Some((Vec2::from(100, 100), Vec2::from(500, 200)))
+    /// );
+    /// ```
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        let (mut xs, mut ys) = (Vec::new(), Vec::new());
+        let collections = core::iter::once(&self.events)
+            .chain(self.loops.iter().map(|loop_| &loop_.events))
+            .chain(self.triggers.iter().map(|trigger| &trigger.events));
+        for events in collections {
+            for event in unique_events(&events.move_) {
+                let positions = match event {
+                    Move::Static(_, _, pos) => [*pos, *pos],
+                    Move::Dynamic(_, _, _, _, start_pos, end_pos) => [*start_pos, *end_pos],
+                };
+                xs.extend(positions.iter().map(|pos| pos.x));
+                ys.extend(positions.iter().map(|pos| pos.y));
+            }
+            for event in unique_events(&events.movex_) {
+                match event {
+                    MoveX::Static(_, _, x) => xs.push(*x),
+                    MoveX::Dynamic(_, _, _, _, start_x, end_x) => xs.extend([*start_x, *end_x]),
+                }
+            }
+            for event in unique_events(&events.movey_) {
+                match event {
+                    MoveY::Static(_, _, y) => ys.push(*y),
+                    MoveY::Dynamic(_, _, _, _, start_y, end_y) => ys.extend([*start_y, *end_y]),
+                }
+            }
+        }
+        if xs.is_empty() && ys.is_empty() {
+            return None;
+        }
+
+        let extent = |values: &[Number], initial: Number| {
+            values
+                .iter()
+                .fold((initial, initial), |(min, max), &value| {
+                    (
+                        if value < min { value } else { min },
+                        if value > max { value } else { max },
+                    )
+                })
+        };
+        let (min_x, max_x) = extent(&xs, xs.first().copied().unwrap_or(self.pos.x));
+        let (min_y, max_y) = extent(&ys, ys.first().copied().unwrap_or(self.pos.y));
+        Some((Vec2::from(min_x, min_y), Vec2::from(max_x, max_y)))
+    }
+
+    /// Returns whether the `Sprite` is flipped horizontally at the timestamp `time`
+    ///
+    /// A dynamic [`HFlip`] event applies from its start time until its end time, excluded, while a
+    /// static one applies from its start time on, which is how osu! plays parameter events. The
+    /// events of loops and triggers are ignored.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.hflip_((1000, 2000)).vflip_(1500);
+    ///
+    /// assert!(!sprite.is_hflipped_at(500));
+    /// assert!(sprite.is_hflipped_at(1000));
+    /// assert!(!sprite.is_hflipped_at(2000));
+    /// assert!(!sprite.is_vflipped_at(1000));
+    /// assert!(sprite.is_vflipped_at(3000));
+    /// ```
+    pub fn is_hflipped_at(&self, time: i32) -> bool {
+        parameter_at(&self.events.hflip_, time)
+    }
+
+    /// Returns whether the `Sprite` is flipped vertically at the timestamp `time`
+    ///
+    /// See [`Sprite::is_hflipped_at`] for more details.
+    pub fn is_vflipped_at(&self, time: i32) -> bool {
+        parameter_at(&self.events.vflip_, time)
+    }
+
+    /// Returns whether the `Sprite` is drawn with additive blending at the timestamp `time`
+    ///
+    /// See [`Sprite::is_hflipped_at`] for more details.
+    pub fn is_additive_at(&self, time: i32) -> bool {
+        parameter_at(&self.events.additive_, time)
+    }
+
+    /// Returns the scale of each axis of the `Sprite` at the timestamp `time`
+    ///
+    /// The [`ScaleVec`] events are resolved first. If none of them is active at `time`, the
+    /// uniform [`Scale`] events are used instead, any of the two falling back to the value of the
+    /// closest event of its kind. A `Sprite` without any scale event has its
+    /// [initial scale](Sprite::set_initial_scale), or a scale of `1`.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{utils::Vec2, Sprite};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.scale_((0, 2));
+    /// sprite.scalevec_((1000, 2000, 1, 1, 3, 2));
+    ///
+    /// assert_eq!(sprite.scale_vec_at(500), Vec2::from(2, 2));
+    /// assert_eq!(sprite.scale_vec_at(1500), Vec2::from(2, 1.5));
+    /// ```
+    pub fn scale_vec_at(&self, time: i32) -> Vec2 {
+        let scalevec = event_at(&self.events.scalevec_, time);
+        let scale = event_at(&self.events.scale_, time);
+        match (scalevec, scale) {
+            (Some(scalevec), Some(_)) if self.events.scalevec_.contains(&time) => {
+                scalevec.value_at(time)
+            }
+            (_, Some(scale)) => {
+                let scale = scale.value_at(time);
+                Vec2::from(scale, scale)
+            }
+            (Some(scalevec), None) => scalevec.value_at(time),
+            (None, None) => {
+                let scale = self.scale.unwrap_or(Number::Int(1));
+                Vec2::from(scale, scale)
+            }
+        }
+    }
+
+    /// Returns the start time of the first event of a `Sprite`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// assert_eq!(sprite.start_time(), None);
+    ///
+    /// sprite.move_((100, 200, 0, 0, 320, 240));
+    /// assert_eq!(sprite.start_time(), Some(100));
+    ///
+    /// sprite.fade_((0, 100, 0, 1));
+    /// assert_eq!(sprite.start_time(), Some(0));
+    /// ```
+    pub fn start_time(&self) -> Option<i32> {
+        self.start_time
+    }
+
+    /// Returns the end time of the first event of a `Sprite`
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// assert_eq!(sprite.end_time(), None);
+    ///
+    /// sprite.move_((0, 100, 0, 0, 320, 240));
+    /// assert_eq!(sprite.end_time(), Some(100));
+    ///
+    /// sprite.fade_((100, 200, 1, 0));
+    /// assert_eq!(sprite.end_time(), Some(200));
+    /// ```
+    pub fn end_time(&self) -> Option<i32> {
+        self.end_time
+    }
+
+    /// Returns the earliest start time of an event of a `Sprite` strictly after the timestamp
+    /// `after`, or `None` if no event starts after it
+    ///
+    /// The events repeated by loops are taken into account for each of their iterations.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite
+    ///     .move_((100, 200, 0, 0, 320, 240))
+    ///     .fade_((500, 600, 0, 1))
+    ///     .rotate_((900, 1000, 0, 1));
+    /// assert_eq!(sprite.next_event_time(200), Some(500));
+    /// assert_eq!(sprite.next_event_time(900), None);
+    /// ```
+    pub fn next_event_time(&self, after: i32) -> Option<i32> {
+        self.loops
+            .iter()
+            .map(|loop_| loop_.next_start_time(after))
+            .chain(core::iter::once(self.events.next_start_time(after)))
+            .flatten()
+            .min()
+    }
+
+    /// Returns `true` if the lifetime of a `Sprite` overlaps the window going from `start` to
+    /// `end`, both included
+    ///
+    /// A `Sprite` without any event never overlaps anything.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((100, 900, 0, 0, 320, 240));
+    /// assert!(sprite.overlaps(800, 1000));
+    /// assert!(!sprite.overlaps(1000, 2000));
+    /// ```
+    pub fn overlaps(&self, start: i32, end: i32) -> bool {
+        match (self.start_time, self.end_time) {
+            (Some(start_time), Some(end_time)) => start_time <= end && end_time >= start,
+            _ => false,
+        }
+    }
+
+    /// Returns a copy of the `Sprite` as it would play in a storyboard of length
+    /// `total_duration` played backwards
+    ///
+    /// Every timestamp `t` becomes `total_duration - t`, the start and end values of each event
+    /// are swapped and their easings are reversed.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240));
+    ///
+    /// let reversed = sprite.reversed(5000);
+    /// assert_eq!(reversed.start_time(), Some(4000));
+    /// assert_eq!(reversed.end_time(), Some(5000));
+    /// ```
+    pub fn reversed(&self, total_duration: i32) -> Sprite {
+        Sprite {
+            events: self.events.reversed(total_duration),
+            current_depth: self.current_depth,
+            path: self.path.clone(),
+            pos: self.pos,
+            layer: self.layer,
+            origin: self.origin,
+            color: self.color,
+            scale: self.scale,
+            precision: self.precision,
+            start_time: self.end_time.map(|end_time| total_duration - end_time),
+            end_time: self
+                .start_time
+                .map(|start_time| total_duration - start_time),
+            type_: self.type_.clone(),
+            playfield: self.playfield,
+            lints: self
+                .lints
+                .iter()
+                .map(|lint| match lint {
+                    Lint::OffScreen { time, pos } => Lint::OffScreen {
+                        time: total_duration - time,
+                        pos: *pos,
+                    },
+                })
+                .collect(),
+            loops: self
+                .loops
+                .iter()
+                .map(|loop_| loop_.reversed(total_duration))
+                .collect(),
+            triggers: self
+                .triggers
+                .iter()
+                .map(|trigger| trigger.reversed(total_duration))
+                .collect(),
+            raw_lines: self.raw_lines.clone(),
+            depth: self.depth,
+        }
+    }
+
+    /// Returns a copy of the `Sprite` mirrored about the vertical center of the playfield,
+    /// `x = 320`, which comes handy for symmetric effects
+    ///
+    /// Every x-coordinate `x` of the position and of the [`Move`] and [`MoveX`] events becomes
+    /// `640 - x`, and the `Sprite` is flipped horizontally over its whole lifetime wherever it
+    /// wasn't already, and unflipped wherever it was, a static [`HFlip`] flipping it from its time
+    /// onward. The [`HFlip`] events of its loops are kept as they are.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 320, 240, 100, 240));
+    ///
+    /// let mirrored = sprite.mirrored_x();
+    /// assert!(mirrored.to_str().contains(" M,0,0,1000,320,240,540,240\n"));
+    /// assert!(mirrored.to_str().contains(" P,0,0,1000,H\n"));
+    /// ```
+    pub fn mirrored_x(&self) -> Sprite {
+        let mut events = self.events.mirrored_x();
+        events.hflip_ = IntervalMap::new();
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            // The flipped intervals, sorted and merged, a static flip lasting until the end
+            let mut flipped: Vec<(i32, i32)> = Vec::new();
+            for event in unique_events(&self.events.hflip_) {
+                let (start, end) = match *event {
+                    HFlip::Static(_, time) => (time, end_time),
+                    HFlip::Dynamic(_, _, start, end) => (start, end),
+                };
+                match flipped.last_mut() {
+                    Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                    _ => flipped.push((start, end)),
+                }
+            }
+            let mut cursor = start_time;
+            for (start, end) in flipped
+                .into_iter()
+                .chain(core::iter::once((end_time, end_time)))
+            {
+                if cursor < start {
+                    let event = HFlip::Dynamic(self.current_depth, Easing::Linear, cursor, start);
+                    events.hflip_.push(cursor..start, event);
+                }
+                cursor = cursor.max(end);
+            }
+        }
+
+        Sprite {
+            events,
+            current_depth: self.current_depth,
+            path: self.path.clone(),
+            pos: mirror_vec2_x(self.pos),
+            layer: self.layer,
+            origin: self.origin,
+            color: self.color,
+            scale: self.scale,
+            precision: self.precision,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            type_: self.type_.clone(),
+            playfield: self.playfield,
+            lints: self
+                .lints
+                .iter()
+                .map(|lint| match lint {
+                    Lint::OffScreen { time, pos } => Lint::OffScreen {
+                        time: *time,
+                        pos: mirror_vec2_x(*pos),
+                    },
+                })
+                .collect(),
+            loops: self
+                .loops
+                .iter()
+                .map(|loop_| Loop {
+                    events: loop_.events.mirrored_x(),
+                    ..loop_.clone()
+                })
+                .collect(),
+            triggers: self
+                .triggers
+                .iter()
+                .map(|trigger| Trigger {
+                    events: trigger.events.mirrored_x(),
+                    ..trigger.clone()
+                })
+                .collect(),
+            raw_lines: self.raw_lines.clone(),
+            depth: self.depth,
+        }
+    }
+
+    /// Pushes a copy of every event of `other` to the `Sprite`
+    ///
+    /// Both `Sprite`s must share the same path and [`Origin`], a [`SpriteMismatch`] is returned
+    /// otherwise and the `Sprite` is left untouched.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240));
+    ///
+    /// let mut fading = Sprite::new("res/sprite.png");
+    /// fading.fade_((1000, 2000, 1, 0));
+    ///
+    /// sprite.append_events_from(&fading).unwrap();
+    /// assert_eq!(sprite.end_time(), Some(2000));
+    /// ```
+    pub fn append_events_from(&mut self, other: &Sprite) -> Result<(), SpriteMismatch> {
+        if self.path != other.path {
+            return Err(SpriteMismatch::Path(self.path.clone(), other.path.clone()));
+        }
+        if self.origin != other.origin {
+            return Err(SpriteMismatch::Origin(self.origin, other.origin));
+        }
+
+        for event in unique_events(&other.events.move_) {
+            self.lint_move(event);
+        }
+        self.events.append(&other.events);
+        self.loops.extend(other.loops.iter().cloned());
+        self.triggers.extend(other.triggers.iter().cloned());
+        self.start_time = match (self.start_time, other.start_time) {
+            (Some(start_time), Some(other_start_time)) => Some(start_time.min(other_start_time)),
+            (start_time, other_start_time) => start_time.or(other_start_time),
+        };
+        self.end_time = match (self.end_time, other.end_time) {
+            (Some(end_time), Some(other_end_time)) => Some(end_time.max(other_end_time)),
+            (end_time, other_end_time) => end_time.or(other_end_time),
+        };
+        Ok(())
+    }
+
+    /// Starts a `L` block repeating `loop_count` times from `start_time`, returning a
+    /// [`SpriteLoop`] to push the repeated events to
+    ///
+    /// The events of the loop are written under its `L` line, indented by one more level.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite
+    ///     .loop_(1000, 4)
+    ///     .fade_((0, 500, 0, 1))
+    ///     .fade_((500, 1000, 1, 0));
+    ///
+    /// assert!(sprite
+    ///     .to_str()
+    ///     .ends_with(" L,1000,4\n  F,0,0,500,0,1\n  F,0,500,1000,1,0\n"));
+    /// assert_eq!(sprite.start_time(), Some(1000));
+    /// assert_eq!(sprite.end_time(), Some(5000));
+    /// ```
+    pub fn loop_(&mut self, start_time: i32, loop_count: u32) -> SpriteLoop<'_> {
+        self.loops.push(Loop {
+            start_time,
+            loop_count,
+            events: EventCollection::new(),
+            raw_lines: Vec::new(),
+        });
+        SpriteLoop {
+            index: self.loops.len() - 1,
+            sprite: self,
+        }
+    }
+
+    /// Starts a `T` block firing on `trigger_type` between `start_time` and `end_time`, returning
+    /// a [`SpriteTrigger`] to push the events played on firing to
+    ///
+    /// The events of the trigger are written under its `T` line, indented by one more level.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Sprite, TriggerType};
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.fade_((0, 10000, 0.5, 0.5));
+    /// sprite
+    ///     .trigger_(TriggerType::HitSoundClap, 0, 10000)
+    ///     .fade_((0, 200, 1, 0.5));
+    ///
+    /// assert!(sprite
+    ///     .to_str()
+    ///     .ends_with(" T,HitSoundClap,0,10000\n  F,0,0,200,1,0.5\n"));
+    /// assert_eq!(sprite.end_time(), Some(10000));
+    /// ```
+    pub fn trigger_(
+        &mut self,
+        trigger_type: TriggerType,
+        start_time: i32,
+        end_time: i32,
+    ) -> SpriteTrigger<'_> {
+        self.triggers.push(Trigger {
+            trigger_type,
+            start_time,
+            end_time,
+            events: EventCollection::new(),
+            raw_lines: Vec::new(),
+        });
+        SpriteTrigger {
+            index: self.triggers.len() - 1,
+            sprite: self,
+        }
+    }
+
+    // The last loop of the `Sprite`, to push more events to
+    pub(crate) fn last_loop(&mut self) -> Option<SpriteLoop<'_>> {
+        let index = self.loops.len().checked_sub(1)?;
+        Some(SpriteLoop {
+            sprite: self,
+            index,
+        })
+    }
+
+    // The last trigger of the `Sprite`, to push more events to
+    pub(crate) fn last_trigger(&mut self) -> Option<SpriteTrigger<'_>> {
+        let index = self.triggers.len().checked_sub(1)?;
+        Some(SpriteTrigger {
+            sprite: self,
+            index,
+        })
+    }
+
+    /// Rewrites the events of the `Sprite` repeating every `period` milliseconds into a single
+    /// loop, returning whether any event was compressed
+    ///
+    /// Starting from the first event, the events are split in cycles of length `period`. The
+    /// leading cycles identical to the first one are replaced by a `L` block holding the events of
+    /// the first cycle, the following events being left untouched. At least two identical cycles
+    /// are needed, and the events of the first cycle must last exactly `period` milliseconds for
+    /// the loop to play at the same pace.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// for i in 0..4 {
+    ///     sprite.rotate_((i * 1000, (i + 1) * 1000, 0, 3));
+    /// }
+    ///
+    /// assert!(sprite.auto_loop(1000));
+    /// assert!(sprite.to_str().contains(" L,0,4\n  R,0,0,1000,0,3\n"));
+    /// ```
+    pub fn auto_loop(&mut self, period: i32) -> bool {
+        let origin = match self.events.start_time() {
+            Some(origin) if period > 0 => origin,
+            _ => return false,
+        };
+
+        let cycles = self.events.cycles(origin, period);
+        let count = cycles
+            .iter()
+            .take_while(|lines| **lines == cycles[0])
+            .count();
+        if count < 2 {
+            return false;
+        }
+
+        let (events, rest) =
+            self.events
+                .split_cycles(origin, period, count as i32, self.current_depth + 1);
+        if events.end_time() != Some(period) {
+            return false;
+        }
+
+        self.events = rest;
+        self.loops.push(Loop {
+            start_time: origin,
+            loop_count: count as u32,
+            events,
+            raw_lines: Vec::new(),
+        });
+        true
+    }
+
+    /// Returns the contents of the `Sprite`
+    ///
+    /// **Warning**: this method is not meant to be used
+    pub fn to_str(&self) -> String {
+        self.render(false)
+    }
+
+    // The contents of the `Sprite`, `LoopForever` being written out on animations only if
+    // `explicit_loop_type` is set
+    pub(crate) fn render(&self, explicit_loop_type: bool) -> String {
+        self.declaration(explicit_loop_type) + &self.commands_to_str()
+    }
+
+    /// Writes the contents of the `Sprite` to `w` line by line, without rendering them as a whole
+    ///
+    /// The bytes written are exactly the ones of [`Sprite::to_str`].
+    ///
+    /// Requires the `std` feature
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240));
+    ///
+    /// let mut bytes = Vec::new();
+    /// sprite.write_to(&mut bytes).unwrap();
+    /// assert_eq!(bytes, sprite.to_str().into_bytes());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.write_rendered(w, false)
+    }
+
+    // Writes what `render` returns to `w`
+    #[cfg(feature = "std")]
+    pub(crate) fn write_rendered<W: Write>(
+        &self,
+        w: &mut W,
+        explicit_loop_type: bool,
+    ) -> io::Result<()> {
+        w.write_all(self.declaration(explicit_loop_type).as_bytes())?;
+        w.write_all(self.initial_color_to_str().as_bytes())?;
+        w.write_all(self.initial_scale_to_str().as_bytes())?;
+        self.events.write_to(w, self.precision)?;
+        for loop_ in &self.loops {
+            loop_.write_to(w, self.current_depth, self.precision)?;
+        }
+        for trigger in &self.triggers {
+            trigger.write_to(w, self.current_depth, self.precision)?;
+        }
+        write_raw_lines(w, &self.raw_lines)
+    }
+
+    // Number of bytes `render` writes, see `Sprite::byte_len`
+    pub(crate) fn rendered_len(&self, explicit_loop_type: bool) -> usize {
+        let loops = self
+            .loops
+            .iter()
+            .map(|loop_| loop_.byte_len(self.current_depth, self.precision))
+            .sum::<usize>();
+        let triggers = self
+            .triggers
+            .iter()
+            .map(|trigger| trigger.byte_len(self.current_depth, self.precision))
+            .sum::<usize>();
+        self.declaration(explicit_loop_type).len()
+            + self.initial_color_to_str().len()
+            + self.initial_scale_to_str().len()
+            + self.events.byte_len(self.precision)
+            + loops
+            + triggers
+            + raw_lines_len(&self.raw_lines)
+    }
+
+    // The line declaring the `Sprite`, see `render`
+    fn declaration(&self, explicit_loop_type: bool) -> String {
+        match &self.type_ {
+            SpriteType::Sprite => {
+                return format!(
+                    "Sprite,{},{},\"{}\",{},{}\n",
+                    self.layer, self.origin, self.path, self.pos.x, self.pos.y,
+                );
+            }
+            SpriteType::Animation {
+                frame_count,
+                frame_delay,
+                loop_type,
+            } => {
+                return format!(
+                    "Animation,{},{},\"{}\",{},{},{},{}{}\n",
+                    self.layer,
+                    self.origin,
+                    self.path,
+                    self.pos.x,
+                    self.pos.y,
+                    frame_count,
+                    frame_delay,
+                    match loop_type {
+                        LoopType::LoopOnce => ",LoopOnce",
+                        LoopType::LoopForever if explicit_loop_type => ",LoopForever",
+                        // defaults to LoopForever if not specified
+                        LoopType::LoopForever => "",
+                    },
+                );
+            }
+        }
+    }
+
+    /// Returns the number of lines the `Sprite` renders to, without rendering it
+    ///
+    /// The declaration of the `Sprite`, its events and the headers of its loops are all counted,
+    /// an event pushed several times being counted once, like it is rendered. Only one line is
+    /// rendered at a time, which is much cheaper than counting the lines of [`Sprite::to_str`].
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240)).fade_((0, 1));
+    /// assert_eq!(sprite.line_count(), 3);
+    /// assert_eq!(sprite.line_count(), sprite.to_str().lines().count());
+    /// ```
+    pub fn line_count(&self) -> usize {
+        1 + self.color.iter().count()
+            + self.scale.iter().count()
+            + self.events.line_count(self.precision)
+            + self
+                .loops
+                .iter()
+                .map(|loop_| loop_.line_count(self.precision))
+                .sum::<usize>()
+            + self
+                .triggers
+                .iter()
+                .map(|trigger| trigger.line_count(self.precision))
+                .sum::<usize>()
+            + self.raw_lines.len()
+    }
+
+    /// Returns the number of bytes the `Sprite` renders to, without rendering it as a whole
+    ///
+    /// Only one line is rendered at a time, which comes handy to estimate the size of huge
+    /// storyboards. An event pushed several times is only counted once, like it is rendered.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240)).fade_((0, 1));
+    /// sprite.loop_(1000, 2).scale_((0, 500, 1, 2));
+    /// assert_eq!(sprite.byte_len(), sprite.to_str().len());
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        self.rendered_len(false)
+    }
+
+    /// Returns the number of events of each kind pushed to the `Sprite`
+    ///
+    /// The events of a loop are counted once, regardless of how many times the loop repeats them.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let mut sprite = Sprite::new("res/sprite.png");
+    /// sprite.move_((0, 1000, 0, 0, 320, 240)).fade_((0, 1));
+    /// assert_eq!(sprite.event_kind_counts().move_, 1);
+    /// assert_eq!(sprite.event_kind_counts().total(), 2);
+    /// ```
+    pub fn event_kind_counts(&self) -> EventKindCounts {
+        self.loops
+            .iter()
+            .map(|loop_| &loop_.events)
+            .chain(self.triggers.iter().map(|trigger| &trigger.events))
+            .fold(self.events.kind_counts(), |counts, events| {
+                counts + events.kind_counts()
+            })
+    }
+
+    fn commands_to_str(&self) -> String {
+        let loops: String = self
+            .loops
+            .iter()
+            .map(|loop_| loop_.to_str(self.current_depth, self.precision))
+            .collect();
+        let triggers: String = self
+            .triggers
+            .iter()
+            .map(|trigger| trigger.to_str(self.current_depth, self.precision))
+            .collect();
+        self.initial_color_to_str()
+            + &self.initial_scale_to_str()
+            + &self.events.to_str(self.precision)
+            + &loops
+            + &triggers
+            + &raw_lines_to_str(&self.raw_lines)
+    }
+
+    fn initial_color_to_str(&self) -> String {
+        match self.color {
+            Some(color) => {
+                Color::Static(self.current_depth, self.start_time.unwrap_or(0), color).to_line()
+                    + "\n"
+            }
+            None => String::new(),
+        }
+    }
+
+    fn initial_scale_to_str(&self) -> String {
+        match self.scale {
+            Some(scale) => {
+                Scale::Static(self.current_depth, self.start_time.unwrap_or(0), scale)
+                    .to_line_with_precision(self.precision.unwrap_or(DEFAULT_PRECISION))
+                    + "\n"
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Sets the screen depth of the `Sprite` within its [`Module`](crate::Module), `Sprite`s of a
+    /// greater depth drawing above the others
+    ///
+    /// osu! draws the `Sprite`s declared later above the ones declared before them, so a `Module`
+    /// renders its `Sprite`s sorted by depth, the ones sharing a depth keeping the order they were
+    /// pushed in. A `Sprite` without a depth is given one above every `Sprite` pushed before it by
+    /// [`Module::push`](crate::Module::push).
+    ///
+    /// The screen depth has nothing to do with the depth of [`Event::set_depth`], which is the
+    /// indentation of the commands of the `Sprite` and grows with the nesting of loops and
+    /// triggers.
+    ///
+    /// Example:
+    /// ```
+    /// use osb::{Layer, Module, Sprite};
+    ///
+    /// let mut module = Module::new(Layer::Background);
+    /// let mut above = Sprite::new("res/above.png");
+    /// above.set_depth(1);
+    /// module.push(above);
+    /// let mut below = Sprite::new("res/below.png");
+    /// below.set_depth(0);
+    /// module.push(below);
+    ///
+    /// assert!(module.output().starts_with("Sprite,Background,Centre,\"res/below.png\""));
+    /// ```
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = Some(depth);
+    }
+
+    /// Returns the screen depth of the `Sprite`, see [`Sprite::set_depth`]
+    ///
+    /// Example:
+    /// ```
+    /// use osb::Sprite;
+    ///
+    /// let sprite = Sprite::new("res/sprite.png");
+    /// assert_eq!(sprite.depth(), None);
+    /// ```
+    pub fn depth(&self) -> Option<usize> {
+        self.depth
+    }
+
+    /// Sets the [`Layer`] of the `Sprite`
+    ///
+    /// **Warning**: this method is not meant to be used
+    pub fn set_layer(&mut self, layer: Layer) {
+        self.layer = layer;
+    }
+}
+
+/// Creates a `Sprite` with the path of the file
+///
+/// Example:
+/// ```
+/// use osb::Sprite;
+/// let path = String::from("res/sprite.png");
+/// let mut sprite = Sprite::new(path);
+/// ```
+impl Into<Sprite> for String {
+    fn into(self) -> Sprite {
+        Sprite::with_path(self)
+    }
+}
+
+/// Creates a `Sprite` with the path of the file
+///
+/// Example:
+/// ```
+/// use osb::Sprite;
+/// let path = "res/sprite.png";
+/// let mut sprite = Sprite::new(path);
+/// ```
+impl Into<Sprite> for &str {
+    fn into(self) -> Sprite {
+        Sprite::with_path(String::from(self))
+    }
+}
+
+// The path of a file as osu! expects it, with forward slashes whatever the platform
+#[cfg(feature = "std")]
+fn osu_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Creates a `Sprite` with the path of the file, its separators being turned into forward slashes
+/// as osu! expects
+///
+/// Requires the `std` feature
+///
+/// Example:
+/// ```
+/// use osb::Sprite;
+/// use std::path::PathBuf;
+/// let path = PathBuf::from("res").join("sprite.png");
+/// let mut sprite = Sprite::new(path);
+/// assert!(sprite.to_str().contains("\"res/sprite.png\""));
+/// ```
+#[cfg(feature = "std")]
+impl Into<Sprite> for PathBuf {
+    fn into(self) -> Sprite {
+        Sprite::with_path(osu_path(&self))
+    }
+}
+
+/// Creates a `Sprite` with the path of the file, its separators being turned into forward slashes
+/// as osu! expects
+///
+/// Requires the `std` feature
+///
+/// Example:
+/// ```
+/// use osb::Sprite;
+/// use std::path::Path;
+/// let path = Path::new("res/sprite.png");
+/// let mut sprite = Sprite::new(path);
+/// ```
+#[cfg(feature = "std")]
+impl Into<Sprite> for &Path {
+    fn into(self) -> Sprite {
+        Sprite::with_path(osu_path(self))
+    }
+}
+
+/// Creates a `Sprite` with the origin and path of the file
+///
+/// Example:
+/// ```
+/// use osb::{Origin, Sprite};
+/// let origin = Origin::Centre;
+/// let path = String::from("res/sprite.png");
+/// let mut sprite = Sprite::new((origin, path));
+/// ```
+impl Into<Sprite> for (Origin, String) {
+    fn into(self) -> Sprite {
+        Sprite {
+            origin: self.0,
+            ..Sprite::with_path(self.1)
+        }
+    }
+}
 
 /// Creates a `Sprite` with the origin and the path of the file
 ///
@@ -540,15 +3185,8 @@ impl Into<Sprite> for (Origin, String) {
 impl Into<Sprite> for (Origin, &str) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.1),
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            ..Sprite::with_path(String::from(self.1))
         }
     }
 }
@@ -565,15 +3203,8 @@ impl Into<Sprite> for (Origin, &str) {
 impl Into<Sprite> for (String, Vec2) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.0,
-            pos: self.1,
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: self.1.normalized(),
+            ..Sprite::with_path(self.0)
         }
     }
 }
@@ -595,15 +3226,8 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.0,
-            pos: Vec2::from(self.1, self.2),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: Vec2::from(self.1, self.2).normalized(),
+            ..Sprite::with_path(self.0)
         }
     }
 }
@@ -620,15 +3244,8 @@ where
 impl Into<Sprite> for (&str, Vec2) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.0),
-            pos: self.1,
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: self.1.normalized(),
+            ..Sprite::with_path(String::from(self.0))
         }
     }
 }
@@ -650,15 +3267,8 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.0),
-            pos: Vec2::from(self.1, self.2),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: Vec2::from(self.1, self.2).normalized(),
+            ..Sprite::with_path(String::from(self.0))
         }
     }
 }
@@ -676,15 +3286,9 @@ where
 impl Into<Sprite> for (Origin, String, Vec2) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.1,
-            pos: self.2,
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: self.2.normalized(),
+            ..Sprite::with_path(self.1)
         }
     }
 }
@@ -707,15 +3311,9 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.1,
-            pos: Vec2::from(self.2, self.3),
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: Vec2::from(self.2, self.3).normalized(),
+            ..Sprite::with_path(self.1)
         }
     }
 }
@@ -733,15 +3331,9 @@ where
 impl Into<Sprite> for (Origin, &str, Vec2) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.1),
-            pos: self.2,
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: self.2.normalized(),
+            ..Sprite::with_path(String::from(self.1))
         }
     }
 }
@@ -764,15 +3356,9 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.1),
-            pos: Vec2::from(self.2, self.3),
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
-            type_: SpriteType::Sprite,
+            pos: Vec2::from(self.2, self.3).normalized(),
+            ..Sprite::with_path(String::from(self.1))
         }
     }
 }
@@ -791,19 +3377,12 @@ where
 impl Into<Sprite> for (String, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.0,
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
             type_: SpriteType::Animation {
                 frame_count: self.1,
                 frame_delay: self.2,
                 loop_type: self.3,
             },
+            ..Sprite::with_path(self.0)
         }
     }
 }
@@ -822,19 +3401,12 @@ impl Into<Sprite> for (String, u32, u32, LoopType) {
 impl Into<Sprite> for (&str, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.0),
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
             type_: SpriteType::Animation {
                 frame_count: self.1,
                 frame_delay: self.2,
                 loop_type: self.3,
             },
+            ..Sprite::with_path(String::from(self.0))
         }
     }
 }
@@ -854,19 +3426,13 @@ impl Into<Sprite> for (&str, u32, u32, LoopType) {
 impl Into<Sprite> for (Origin, String, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.1,
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
             type_: SpriteType::Animation {
                 frame_count: self.2,
                 frame_delay: self.3,
                 loop_type: self.4,
             },
+            ..Sprite::with_path(self.1)
         }
     }
 }
@@ -886,19 +3452,13 @@ impl Into<Sprite> for (Origin, String, u32, u32, LoopType) {
 impl Into<Sprite> for (Origin, &str, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.1),
-            pos: Vec2::from(320, 240),
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
             type_: SpriteType::Animation {
                 frame_count: self.2,
                 frame_delay: self.3,
                 loop_type: self.4,
             },
+            ..Sprite::with_path(String::from(self.1))
         }
     }
 }
@@ -918,19 +3478,13 @@ impl Into<Sprite> for (Origin, &str, u32, u32, LoopType) {
 impl Into<Sprite> for (String, Vec2, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.0,
-            pos: self.1,
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
+            pos: self.1.normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.2,
                 frame_delay: self.3,
                 loop_type: self.4,
             },
+            ..Sprite::with_path(self.0)
         }
     }
 }
@@ -955,19 +3509,13 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.0,
-            pos: Vec2::from(self.1, self.2),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
+            pos: Vec2::from(self.1, self.2).normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.3,
                 frame_delay: self.4,
                 loop_type: self.5,
             },
+            ..Sprite::with_path(self.0)
         }
     }
 }
@@ -987,19 +3535,13 @@ where
 impl Into<Sprite> for (&str, Vec2, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.0),
-            pos: self.1,
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
+            pos: self.1.normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.2,
                 frame_delay: self.3,
                 loop_type: self.4,
             },
+            ..Sprite::with_path(String::from(self.0))
         }
     }
 }
@@ -1024,19 +3566,13 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.0),
-            pos: Vec2::from(self.1, self.2),
-            layer: Layer::Background,
-            origin: Origin::Centre,
-            start_time: None,
-            end_time: None,
+            pos: Vec2::from(self.1, self.2).normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.3,
                 frame_delay: self.4,
                 loop_type: self.5,
             },
+            ..Sprite::with_path(String::from(self.0))
         }
     }
 }
@@ -1057,19 +3593,14 @@ where
 impl Into<Sprite> for (Origin, String, Vec2, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.1,
-            pos: self.2,
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
+            pos: self.2.normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.3,
                 frame_delay: self.4,
                 loop_type: self.5,
             },
+            ..Sprite::with_path(self.1)
         }
     }
 }
@@ -1095,19 +3626,14 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: self.1,
-            pos: Vec2::from(self.2, self.3),
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
+            pos: Vec2::from(self.2, self.3).normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.4,
                 frame_delay: self.5,
                 loop_type: self.6,
             },
+            ..Sprite::with_path(self.1)
         }
     }
 }
@@ -1128,19 +3654,14 @@ where
 impl Into<Sprite> for (Origin, &str, Vec2, u32, u32, LoopType) {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.1),
-            pos: self.2,
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
+            pos: self.2.normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.3,
                 frame_delay: self.4,
                 loop_type: self.5,
             },
+            ..Sprite::with_path(String::from(self.1))
         }
     }
 }
@@ -1166,26 +3687,51 @@ where
 {
     fn into(self) -> Sprite {
         Sprite {
-            events: EventCollection::new(),
-            current_depth: 0,
-            path: String::from(self.1),
-            pos: Vec2::from(self.2, self.3),
-            layer: Layer::Background,
             origin: self.0,
-            start_time: None,
-            end_time: None,
+            pos: Vec2::from(self.2, self.3).normalized(),
             type_: SpriteType::Animation {
                 frame_count: self.4,
                 frame_delay: self.5,
                 loop_type: self.6,
             },
+            ..Sprite::with_path(String::from(self.1))
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{LoopType, Sprite};
+    use crate::{
+        utils::{Color, Number, Vec2},
+        Easing, EventKind, Lint, LoopType, Sprite, SpriteMismatch, SpriteWarning, Transform,
+        TriggerType,
+    };
+
+    #[test]
+    fn chaining() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((0, 320, 240))
+            .fade_((0, 1000, 0, 1))
+            .scale_((0, 1));
+        assert_eq!(sprite.to_str().lines().count(), 4);
+        assert_eq!(sprite.end_time(), Some(1000));
+    }
+
+    #[test]
+    fn initial_color() {
+        let mut sprite = Sprite::new("sb/sprite.jpg").with_color(Color::from(42, 42, 42));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n C,0,0,,42,42,42\n",
+            sprite.to_str()
+        );
+
+        sprite.move_((500, 1000, 0, 0, 320, 240));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n C,0,500,,42,42,42\n M,0,500,1000,0,0,320,240\n",
+            sprite.to_str()
+        );
+    }
 
     #[test]
     fn animation() {
@@ -1195,4 +3741,755 @@ mod tests {
             sprite.to_str()
         );
     }
+
+    #[test]
+    fn whole_float_position() {
+        let sprite = Sprite::new(("a.png", 320.0, 240.0));
+        assert_eq!(sprite.get_x(), Number::Int(320));
+        assert_eq!(
+            "Sprite,Background,Centre,\"a.png\",320,240\n",
+            sprite.to_str()
+        );
+
+        let sprite = Sprite::new(("a.png", 320.5, 240.0));
+        assert_eq!(sprite.get_x(), Number::Float(320.5));
+        assert_eq!(
+            "Sprite,Background,Centre,\"a.png\",320.5,240\n",
+            sprite.to_str()
+        );
+    }
+
+    #[test]
+    fn append_events_from() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.move_((500, 1000, 0, 0, 320, 240)).fade_((500, 1));
+
+        let mut other = Sprite::new("sb/sprite.jpg");
+        other.scale_((0, 1000, 0, 1)).rotate_((1000, 2000, 0, 1));
+
+        assert!(sprite.append_events_from(&other).is_ok());
+        assert_eq!(sprite.to_str().lines().count(), 5);
+        assert_eq!(sprite.start_time(), Some(0));
+        assert_eq!(sprite.end_time(), Some(2000));
+
+        let other = Sprite::new("sb/other.jpg");
+        assert_eq!(
+            sprite.append_events_from(&other),
+            Err(SpriteMismatch::Path(
+                String::from("sb/sprite.jpg"),
+                String::from("sb/other.jpg")
+            ))
+        );
+    }
+
+    #[test]
+    fn clamp_to_playfield() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.move_((0, 2000, 240));
+        assert!(sprite.lint().is_empty());
+
+        sprite.set_clamp_to_playfield(false);
+        sprite.move_((0, 1000, 320, 240, 2000, 240));
+        assert_eq!(
+            sprite.lint(),
+            &[Lint::OffScreen {
+                time: 1000,
+                pos: Vec2::from(2000, 240)
+            }]
+        );
+        assert!(sprite.to_str().contains(" M,0,0,1000,320,240,2000,240\n"));
+
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.set_clamp_to_playfield(true);
+        sprite.move_((0, -100, 240));
+        assert!(sprite.lint().is_empty());
+    }
+
+    #[test]
+    fn event_order() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((1000, 2000, 0, 0, 320, 240))
+            .move_((500, 1000, 0, 0, 0, 0))
+            .move_((1000, 2000, 0, 0, 320, 240))
+            .move_((1000, 1500, 320, 240, 0, 0));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,500,1000,0,0,0,0\n M,0,1000,2000,0,0,320,240\n M,0,1000,1500,320,240,0,0\n",
+            sprite.to_str()
+        );
+    }
+
+    #[test]
+    fn pos_at() {
+        let mut sprite = Sprite::new(("sb/sprite.jpg", Vec2::from(10, 20)));
+        assert_eq!(sprite.pos_at(0), Vec2::from(10, 20));
+
+        sprite
+            .move_((1000, 100, 100))
+            .move_((Easing::QuadIn, 2000, 3000, 0, 0, 100, 200))
+            .movey_((2500, 3500, 0, 100));
+        assert_eq!(sprite.pos_at(999), Vec2::from(10, 20));
+        assert_eq!(sprite.pos_at(1000), Vec2::from(100, 100));
+        assert_eq!(sprite.pos_at(1500), Vec2::from(100, 100));
+        assert_eq!(sprite.pos_at(2500), Vec2::from(25, 0));
+        assert_eq!(sprite.pos_at(3000), Vec2::from(100, 50));
+        assert_eq!(sprite.pos_at(5000), Vec2::from(100, 100));
+    }
+
+    #[test]
+    fn pos_at_move_and_movex() {
+        let mut sprite = Sprite::new(("sb/sprite.jpg", Vec2::from(10, 20)));
+        sprite
+            .movex_((500, 50))
+            .move_((1000, 2000, 0, 0, 100, 200))
+            .movex_((1200, 1400, 300, 400))
+            .movex_((2000, 2500, 0, 50))
+            .move_((2500, 3000, 100, 100, 200, 200));
+
+        // The `MoveX` event started before the `Move` event is overridden by it
+        assert_eq!(sprite.pos_at(500), Vec2::from(50, 20));
+        assert_eq!(sprite.pos_at(1000), Vec2::from(0, 0));
+        // The running `Move` event keeps the y-axis while the later `MoveX` event sets the x-axis
+        assert_eq!(sprite.pos_at(1300).x, Number::Int(350));
+        assert_eq!(sprite.pos_at(1500), Vec2::from(400, 100));
+        // Starting with the end of the `Move` event, the `MoveX` event wins the tie
+        assert_eq!(sprite.pos_at(2000), Vec2::from(0, 200));
+        assert_eq!(sprite.pos_at(2250), Vec2::from(25, 200));
+        assert_eq!(sprite.pos_at(2750), Vec2::from(150, 150));
+    }
+
+    #[test]
+    fn auto_loop() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        for cycle in 0..3 {
+            let time = 1000 + cycle * 1000;
+            sprite
+                .fade_((time, time + 500, 0, 1))
+                .fade_((time + 500, time + 1000, 1, 0));
+        }
+        sprite.move_((4000, 320, 240));
+
+        assert!(sprite.auto_loop(1000));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,4000,,320,240\n L,1000,3\n  F,0,0,500,0,1\n  F,0,500,1000,1,0\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(1000));
+        assert_eq!(sprite.end_time(), Some(4000));
+
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.fade_((0, 500, 0, 1)).fade_((1000, 1500, 0, 1));
+        assert!(!sprite.auto_loop(1000));
+    }
+
+    #[test]
+    fn scale_vec_at() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        assert_eq!(sprite.scale_vec_at(0), Vec2::from(1, 1));
+
+        sprite.scalevec_((1000, 2000, 1, 2, 2, 4));
+        assert_eq!(sprite.scale_vec_at(1500), Vec2::from(1.5, 3));
+        assert_eq!(sprite.scale_vec_at(0), Vec2::from(1, 2));
+        assert_eq!(sprite.scale_vec_at(3000), Vec2::from(2, 4));
+
+        sprite.scale_((0, 1000, 0.5, 1));
+        assert_eq!(sprite.scale_vec_at(500), Vec2::from(0.75, 0.75));
+        assert_eq!(sprite.scale_vec_at(1500), Vec2::from(1.5, 3));
+    }
+
+    #[test]
+    fn additive() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.additive();
+        assert_eq!(sprite.to_str().lines().count(), 1);
+
+        sprite.move_((0, 1000, 0, 0, 320, 240)).additive();
+        assert!(sprite.to_str().contains(" P,0,0,1000,A\n"));
+        assert_eq!(sprite.end_time(), Some(1000));
+    }
+
+    #[test]
+    fn color_keyframes() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.color_keyframes(
+            Easing::SineOut,
+            1000,
+            250,
+            &[Color::red(), Color::from(0, 128, 255), Color::white()],
+        );
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n C,16,1000,1250,255,0,0,0,128,255,255,255,255\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(1000));
+        assert_eq!(sprite.end_time(), Some(1500));
+    }
+
+    #[test]
+    fn transform_at() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((1000, 2000, 0, 0, 320, 240))
+            .fade_((1000, 2000, 0, 1))
+            .scale_((1000, 2000, 1, 3));
+
+        assert_eq!(
+            sprite.transform_at(1250),
+            Transform {
+                pos: Vec2::from(80, 60),
+                scale: Vec2::from(1.5, 1.5),
+                rotation: 0.,
+                opacity: 0.25,
+                color: Color::white(),
+            }
+        );
+        assert_eq!(sprite.transform_at(0).pos, Vec2::from(320, 240));
+        assert_eq!(sprite.transform_at(0).opacity, 0.);
+        assert_eq!(sprite.transform_at(3000).pos, Vec2::from(320, 240));
+        assert_eq!(sprite.transform_at(3000).scale, Vec2::from(3, 3));
+    }
+
+    #[test]
+    fn move_events() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.move_((0, 1000, 0, 0, 320, 240)).fade_((500, 1));
+        let mut other = Sprite::new("sb/other.jpg");
+        other.scale_((2000, 3000, 1, 2));
+
+        other.set_events(sprite.take_events());
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n",
+            sprite.to_str()
+        );
+        assert_eq!((sprite.start_time(), sprite.end_time()), (None, None));
+        assert_eq!(other.to_str().lines().count(), 3);
+        assert_eq!(
+            (other.start_time(), other.end_time()),
+            (Some(0), Some(1000))
+        );
+    }
+
+    #[test]
+    fn clamp_times() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((Easing::QuadOut, 0, 2000, 0, 0, 320, 240))
+            .scale_((500, 1000, 1, 2))
+            .fade_((1600, 2000, 1, 0));
+
+        assert_eq!(sprite.clamp_times(1500).end_time(), Some(1500));
+        assert!(sprite.to_str().contains(" M,4,0,1500,0,0,300,225\n"));
+        assert!(sprite.to_str().contains(" S,0,500,1000,1,2\n"));
+        assert!(!sprite.to_str().contains(" F,"));
+        assert_eq!(sprite.start_time(), Some(0));
+        assert_eq!(sprite.end_time(), Some(1500));
+    }
+
+    #[test]
+    fn shift_time() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((Easing::QuadOut, 0, 1000, 0, 0, 320, 240))
+            .fade_((500, 1));
+        sprite.loop_(1000, 2).scale_((0, 500, 1, 2));
+        sprite
+            .trigger_(TriggerType::Failing, 0, 5000)
+            .fade_((0, 100, 1, 0));
+
+        assert_eq!(sprite.shift_time(-500).start_time(), Some(-500));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,4,-500,500,0,0,320,240\n F,0,0,,1\n L,500,2\n  S,0,0,500,1,2\n T,Failing,-500,4500\n  F,0,0,100,1,0\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(-500));
+        assert_eq!(sprite.end_time(), Some(1500));
+    }
+
+    #[test]
+    fn scale_time() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((Easing::QuadOut, 0, 1000, 0, 0, 320, 240))
+            .fade_((2000, 1));
+        sprite.loop_(1000, 2).scale_((0, 500, 1, 2));
+        sprite
+            .trigger_(TriggerType::Failing, 0, 5000)
+            .fade_((0, 100, 1, 0));
+
+        assert_eq!(sprite.scale_time(0.5, 1000).start_time(), Some(500));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,4,500,1000,0,0,320,240\n F,0,1500,,1\n L,1000,2\n  S,0,0,250,1,2\n T,Failing,500,3000\n  F,0,0,50,1,0\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(500));
+        assert_eq!(sprite.end_time(), Some(1500));
+    }
+
+    #[test]
+    fn overlaps() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        assert!(!sprite.overlaps(0, 1000));
+
+        sprite.move_((100, 900, 0, 0, 320, 240));
+        assert!(sprite.overlaps(800, 1000));
+        assert!(sprite.overlaps(0, 100));
+        assert!(sprite.overlaps(200, 300));
+        assert!(!sprite.overlaps(1000, 2000));
+        assert!(!sprite.overlaps(0, 99));
+    }
+
+    #[test]
+    fn bake_move() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.bake_move(
+            Easing::QuadOut,
+            1000,
+            2000,
+            Vec2::from(0, 0),
+            Vec2::from(100, 200),
+            4,
+        );
+
+        let lines = sprite.to_str();
+        assert_eq!(lines.lines().filter(|l| l.starts_with(" M,")).count(), 4);
+        assert!(lines.contains(" M,0,1000,1250,0,0,43.75,87.5\n"));
+        assert!(lines.contains(" M,0,1750,2000,93.75,187.5,100,200\n"));
+        assert_eq!(sprite.start_time(), Some(1000));
+        assert_eq!(sprite.end_time(), Some(2000));
+
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.bake_move(
+            Easing::QuadOut,
+            1000,
+            2000,
+            Vec2::from(0, 0),
+            Vec2::from(100, 200),
+            0,
+        );
+        assert_eq!(sprite.start_time(), None);
+    }
+
+    #[test]
+    fn initial_scale() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.set_initial_scale(Number::Int(2));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n S,0,0,,2\n",
+            sprite.to_str()
+        );
+
+        sprite
+            .scale_((1000, 2000, 2, 3))
+            .move_((500, 1000, 0, 0, 320, 240));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n S,0,500,,2\n M,0,500,1000,0,0,320,240\n S,0,1000,2000,2,3\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.line_count(), 4);
+        assert_eq!(sprite.scale_vec_at(0), Vec2::from(2, 2));
+    }
+
+    #[test]
+    fn precision() {
+        let mut coarse = Sprite::new("sb/sprite.jpg");
+        coarse.set_precision(2);
+        coarse
+            .fade_((0, 1000, 0.123456, 1))
+            .move_((0, 1000, 0, 0, 320.1234, 240));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,0,1000,0,0,320.12,240\n F,0,0,1000,0.12,1\n",
+            coarse.to_str()
+        );
+
+        let mut fine = Sprite::new("sb/sprite.jpg");
+        fine.set_precision(5);
+        fine.set_initial_scale(Number::Float(0.123456));
+        fine.fade_((0, 1000, 0.123456, 1));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n S,0,0,,0.12346\n F,0,0,1000,0.12346,1\n",
+            fine.to_str()
+        );
+    }
+
+    #[test]
+    fn dedup_events() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .fade_((0, 1000, 0, 1))
+            .fade_((0, 1000, 0, 1))
+            .fade_((0, 500, 0, 1))
+            .fade_((Easing::QuadOut, 0, 1000, 0, 1))
+            .move_((0, 320, 240))
+            .move_((0, 320, 240));
+        assert_eq!(sprite.event_kind_counts().total(), 6);
+        assert_eq!(sprite.line_count(), sprite.to_str().lines().count());
+
+        sprite.dedup_events();
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,0,,320,240\n F,0,0,1000,0,1\n F,0,0,500,0,1\n F,4,0,1000,0,1\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.event_kind_counts().total(), 4);
+        assert_eq!(sprite.line_count(), 5);
+    }
+
+    #[test]
+    fn next_event_time() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        assert_eq!(sprite.next_event_time(0), None);
+
+        sprite
+            .move_((100, 320, 240))
+            .fade_((500, 1000, 0, 1))
+            .scale_((900, 1200, 1, 2));
+        assert_eq!(sprite.next_event_time(0), Some(100));
+        assert_eq!(sprite.next_event_time(100), Some(500));
+        assert_eq!(sprite.next_event_time(200), Some(500));
+        assert_eq!(sprite.next_event_time(500), Some(900));
+        assert_eq!(sprite.next_event_time(900), None);
+
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        for cycle in 0..3 {
+            let time = 2000 + cycle * 1000;
+            sprite
+                .fade_((time, time + 500, 0, 1))
+                .fade_((time + 500, time + 1000, 1, 0));
+        }
+        assert!(sprite.auto_loop(1000));
+        assert_eq!(sprite.next_event_time(0), Some(2000));
+        assert_eq!(sprite.next_event_time(2000), Some(2500));
+        assert_eq!(sprite.next_event_time(2700), Some(3000));
+        assert_eq!(sprite.next_event_time(4500), None);
+    }
+
+    #[test]
+    fn line_count() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.set_precision(1);
+        sprite
+            .fade_((0, 1000, 0, 1))
+            .fade_((0, 1000, 0, 1))
+            .rotate_((0, 0.51))
+            .rotate_((0, 0.54));
+        sprite.loop_(1000, 2).scale_((0, 1)).scale_((0, 1));
+        assert_eq!(sprite.event_kind_counts().total(), 6);
+        assert_eq!(sprite.line_count(), sprite.to_str().lines().count());
+        assert_eq!(sprite.line_count(), 5);
+    }
+
+    #[test]
+    fn byte_len() {
+        let mut sprite = Sprite::new("sb/sprite.jpg").with_color(Color::red());
+        assert_eq!(sprite.byte_len(), sprite.to_str().len());
+
+        sprite.set_initial_scale(Number::Float(0.123456));
+        sprite.set_precision(5);
+        sprite
+            .move_((Easing::QuadOut, 0, 1000, 0, 0, 320, 240))
+            .rotate_((0, 1000, 0, 1. / 3.))
+            .hflip_((0, 1000));
+        sprite.loop_(1000, 2).scale_((0, 500, 1, 2));
+        sprite
+            .trigger_(TriggerType::Failing, 0, 5000)
+            .fade_((0, 100, 1, 0));
+        sprite.push_raw(" X,0,0,1000,42");
+        assert_eq!(sprite.byte_len(), sprite.to_str().len());
+
+        // Identical events are rendered once
+        let len = sprite.byte_len();
+        sprite.hflip_((0, 1000));
+        sprite
+            .loop_(3000, 2)
+            .fade_((0, 100, 0, 1))
+            .fade_((0, 100, 0, 1));
+        assert_eq!(sprite.byte_len(), sprite.to_str().len());
+        assert_eq!(
+            sprite.byte_len(),
+            len + " L,3000,2\n  F,0,0,100,0,1\n".len()
+        );
+
+        let animation = Sprite::new(("sb/frame.png", 20, 100, LoopType::LoopOnce));
+        assert_eq!(animation.byte_len(), animation.to_str().len());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_to() {
+        let mut sprite = Sprite::new("sb/sprite.jpg").with_color(Color::red());
+        sprite.set_precision(1);
+        sprite
+            .fade_((0, 1000, 0, 0.51))
+            .fade_((0, 1000, 0, 0.52))
+            .rotate_((0, 1));
+        sprite
+            .trigger_(TriggerType::Failing, 0, 5000)
+            .fade_((0, 100, 1, 0));
+        sprite.push_raw(" X,0,0,1000,42");
+
+        let mut bytes = Vec::new();
+        sprite.write_to(&mut bytes).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), sprite.to_str());
+    }
+
+    #[test]
+    fn validate() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        assert_eq!(sprite.validate(), Ok(()));
+
+        sprite.move_((500, 1500, 0, 0, 320, 240));
+        assert_eq!(sprite.validate(), Err(vec![SpriteWarning::NoFade]));
+
+        sprite.loop_(500, 2).fade_((0, 400, 0, 1));
+        assert_eq!(
+            sprite.validate(),
+            Err(vec![SpriteWarning::BeyondFade {
+                start_time: 500,
+                end_time: 1300
+            }])
+        );
+
+        sprite.fade_((1500, 0)).scale_((1200, 1000, 1, 2));
+        assert_eq!(
+            sprite.validate(),
+            Err(vec![SpriteWarning::EndBeforeStart {
+                start_time: 1200,
+                end_time: 1000
+            }])
+        );
+
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((0, 1000, 0, 0, 320, 240))
+            .trigger_(TriggerType::Failing, 0, 5000)
+            .fade_((0, 100, 1, 0));
+        assert_eq!(sprite.validate(), Ok(()));
+    }
+
+    #[test]
+    fn optimize() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        for frame in 0..5 {
+            let time = frame * 100;
+            sprite
+                .move_((time, 320 + frame * 10, 240 - frame * 5))
+                .fade_((time, frame * frame))
+                .scale_((time, 2));
+        }
+        sprite
+            .rotate_((0, 1))
+            .rotate_((0, 1000, 0, 2))
+            .rotate_((500, 1));
+        sprite.rotate_((1500, 1)).rotate_((2000, 1));
+        assert_eq!(sprite.line_count(), 21);
+
+        sprite.optimize();
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,0,400,320,240,360,220\n F,0,0,,0\n F,0,100,,1\n F,0,200,,4\n F,0,300,,9\n F,0,400,,16\n R,0,0,,1\n R,0,0,1000,0,2\n R,0,500,,1\n R,0,1500,2000,1,1\n S,0,0,400,2,2\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(0));
+        assert_eq!(sprite.end_time(), Some(2000));
+
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.set_precision(1);
+        sprite.fade_((0, 0)).fade_((100, 0.33)).fade_((200, 0.67));
+        sprite.loop_(0, 2).scale_((0, 1)).scale_((100, 1));
+        sprite.optimize();
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n F,0,0,200,0,0.7\n L,0,2\n  S,0,0,100,1,1\n",
+            sprite.to_str()
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let mut sprite = Sprite::new(("sb/sprite.jpg", Vec2::from(320, 240)));
+        sprite.movey_((0, 1000, 100, -50.5));
+        assert_eq!(
+            sprite.bounds(),
+            Some((Vec2::from(320, -50.5), Vec2::from(320, 100)))
+        );
+
+        sprite.move_((500, 600, 700));
+        sprite.loop_(1000, 2).movex_((0, 100, -10, 0));
+        sprite
+            .trigger_(TriggerType::Failing, 0, 5000)
+            .move_((0, 100, 0, 0, 0, 800));
+        assert_eq!(
+            sprite.bounds(),
+            Some((Vec2::from(-10, -50.5), Vec2::from(600, 800)))
+        );
+    }
+
+    #[test]
+    fn parameters_at() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .hflip_((0, 1000))
+            .hflip_((500, 1500))
+            .vflip_(2000)
+            .additive_((1000, 1000));
+        sprite.loop_(0, 2).vflip_((0, 100));
+
+        assert!(sprite.is_hflipped_at(0));
+        assert!(sprite.is_hflipped_at(1200));
+        assert!(!sprite.is_hflipped_at(1500));
+        assert!(!sprite.is_hflipped_at(-1));
+
+        assert!(!sprite.is_vflipped_at(50));
+        assert!(!sprite.is_vflipped_at(1999));
+        assert!(sprite.is_vflipped_at(2000));
+        assert!(sprite.is_vflipped_at(10_000));
+
+        assert!(!sprite.is_additive_at(999));
+        assert!(sprite.is_additive_at(1000));
+        assert!(sprite.is_additive_at(5000));
+    }
+
+    #[test]
+    fn clone() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .move_((Easing::QuadOut, 0, 1000, 0, 0, 320, 240))
+            .scalevec_((0, 1000, 1, 1, 2, 0.5))
+            .color_((500, 255, 128, 0))
+            .fade_((0, 1000, 0, 1))
+            .hflip_(0);
+        sprite.loop_(1000, 2).rotate_((0, 500, 0, 1.5));
+        sprite
+            .trigger_(TriggerType::Passing, 0, 5000)
+            .fade_((0, 100, 1, 0));
+
+        let mut copy = sprite.clone();
+        assert_eq!(copy.to_str(), sprite.to_str());
+
+        copy.movex_((2000, 100));
+        assert!(copy.to_str().contains(" MX,0,2000,,100"));
+        assert!(!sprite.to_str().contains(" MX"));
+    }
+
+    #[test]
+    fn push_raw() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.fade_((0, 1000, 0, 1));
+        sprite.push_raw(" X,0,0,1000,42");
+        sprite.push_raw("  X,0,0,500,7");
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n F,0,0,1000,0,1\n X,0,0,1000,42\n  X,0,0,500,7\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.line_count(), 4);
+        assert!(sprite
+            .reversed(1000)
+            .to_str()
+            .ends_with("\n X,0,0,1000,42\n  X,0,0,500,7\n"));
+    }
+
+    #[test]
+    fn mirrored_x() {
+        let mut sprite = Sprite::new(("sb/sprite.jpg", Vec2::from(100, 100)));
+        sprite
+            .move_((0, 1000, 320, 240, 100, 240))
+            .movex_((1000, 2000, 100, 0.5))
+            .hflip_((500, 1500));
+
+        let mirrored = sprite.mirrored_x();
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",540,100\n M,0,0,1000,320,240,540,240\n MX,0,1000,2000,540,639.5\n P,0,0,500,H\n P,0,1500,2000,H\n",
+            mirrored.to_str()
+        );
+        assert_eq!(mirrored.start_time(), Some(0));
+        assert_eq!(mirrored.end_time(), Some(2000));
+
+        let twice = mirrored.mirrored_x();
+        assert!(twice.to_str().contains(" P,0,500,1500,H\n"));
+        assert!(twice.to_str().contains(" M,0,0,1000,320,240,100,240\n"));
+    }
+
+    #[test]
+    fn mirrored_x_static_hflip() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.move_((0, 1000, 320, 240, 100, 240)).hflip_(500);
+
+        let mirrored = sprite.mirrored_x();
+        assert!(mirrored.to_str().ends_with(" P,0,0,500,H\n"));
+        assert!(!mirrored.is_hflipped_at(750));
+        assert!(mirrored.is_hflipped_at(250));
+    }
+
+    #[test]
+    fn loop_() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.move_((0, 320, 240));
+        sprite
+            .loop_(500, 3)
+            .rotate_((0, 1000, 0, 3))
+            .scale_((Easing::QuadOut, 250, 750, 1, 2));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,0,,320,240\n L,500,3\n  R,0,0,1000,0,3\n  S,4,250,750,1,2\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(0));
+        assert_eq!(sprite.end_time(), Some(3500));
+        assert_eq!(sprite.line_count(), 5);
+        assert_eq!(sprite.event_kind_counts().total(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn path() {
+        use std::path::{Path, PathBuf};
+
+        let sprite = Sprite::new(Path::new("sb\\bg\\sprite.png"));
+        assert!(sprite.to_str().contains("\"sb/bg/sprite.png\""));
+
+        let sprite = Sprite::new(PathBuf::from("sb").join("bg").join("sprite.png"));
+        assert!(sprite.to_str().contains("\"sb/bg/sprite.png\""));
+    }
+
+    #[test]
+    fn clear() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite
+            .fade_((0, 500, 0, 1))
+            .move_((200, 800, 0, 0, 320, 240))
+            .fade_((1500, 3000, 1, 0));
+        sprite.loop_(1000, 2).scale_((0, 500, 1, 2));
+
+        sprite.clear(EventKind::Fade);
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,200,800,0,0,320,240\n L,1000,2\n  S,0,0,500,1,2\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(200));
+        assert_eq!(sprite.end_time(), Some(2000));
+
+        sprite.clear(EventKind::Move).clear(EventKind::Additive);
+        assert_eq!(sprite.start_time(), Some(1000));
+        assert_eq!(sprite.end_time(), Some(2000));
+    }
+
+    #[test]
+    fn trigger_() {
+        let mut sprite = Sprite::new("sb/sprite.jpg");
+        sprite.move_((1000, 2000, 0, 0, 320, 240));
+        sprite
+            .trigger_(TriggerType::Failing, 0, 5000)
+            .fade_((0, 500, 1, 0))
+            .vflip_((0, 500));
+        assert_eq!(
+            "Sprite,Background,Centre,\"sb/sprite.jpg\",320,240\n M,0,1000,2000,0,0,320,240\n T,Failing,0,5000\n  F,0,0,500,1,0\n  P,0,0,500,V\n",
+            sprite.to_str()
+        );
+        assert_eq!(sprite.start_time(), Some(1000));
+        assert_eq!(sprite.end_time(), Some(2000));
+        assert_eq!(sprite.line_count(), 5);
+
+        assert!(sprite
+            .reversed(6000)
+            .to_str()
+            .contains(" T,Failing,1000,6000\n"));
+        sprite.clamp_times(3000);
+        assert!(sprite.to_str().contains(" T,Failing,0,3000\n"));
+    }
 }
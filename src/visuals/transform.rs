@@ -0,0 +1,18 @@
+use crate::utils::{Color, Vec2};
+
+/// The state of a [`Sprite`](crate::Sprite) at a given time
+///
+/// See [`Sprite::transform_at`](crate::Sprite::transform_at).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// Position of the `Sprite`
+    pub pos: Vec2,
+    /// Scale of each axis of the `Sprite`
+    pub scale: Vec2,
+    /// Rotation of the `Sprite`, in radians
+    pub rotation: f32,
+    /// Opacity of the `Sprite`, from 0 to 1
+    pub opacity: f32,
+    /// Colorization of the `Sprite`
+    pub color: Color,
+}